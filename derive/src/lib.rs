@@ -0,0 +1,522 @@
+// This component uses Sciter Engine,
+// copyright Terra Informatica Software, Inc.
+// (http://terrainformatica.com/).
+
+/*!
+
+Derive macros for converting Rust types to and from [`sciter::Value`](https://docs.rs/sciter-rs/latest/sciter/value/struct.Value.html).
+
+`#[derive(FromValue)]` implements the `sciter::FromValue` trait
+and `#[derive(IntoValue)]` implements `From<T> for sciter::Value`.
+
+Unlike [`sciter-serde`](https://docs.rs/sciter-serde) the conversion is done directly
+by the `Value` API and can express Sciter-specific types like colors and durations.
+
+## Representation
+
+* struct with named fields - map, e.g. `{x: 1, y: 2}`;
+* struct with `#[sciter(array)]` and tuple struct - array of the fields;
+* newtype struct (aka `struct Id(u32)`) - the underlying value;
+* unit struct - `null`;
+* unit enum variant - string with its name;
+* other enum variants - map with a single key (the variant name)
+  and the variant data as a value, represented like the structs above.
+
+## Attributes
+
+Container:
+
+* `#[sciter(array)]` - store a struct with named fields as an array (not supported for enums).
+
+Variant:
+
+* `#[sciter(rename = "name")]` - use another name for the variant.
+
+Field:
+
+* `#[sciter(rename = "name")]` - use another key for the field;
+* `#[sciter(default)]` - use `Default::default()` if the field is missing (`undefined`);
+* `#[sciter(default = "path")]` - call `path()` if the field is missing;
+* `#[sciter(color)]` - store `u32` as a Sciter color (see `Value::color`);
+* `#[sciter(duration)]` - store `f64` as a Sciter duration in seconds (see `Value::duration`).
+
+# Examples
+
+```rust
+#[macro_use] extern crate sciter_derive;
+extern crate sciter;
+
+use sciter::{FromValue, Value};
+
+#[derive(Debug, PartialEq, FromValue, IntoValue)]
+struct Theme {
+    name: String,
+    #[sciter(color)]
+    background: u32,
+    #[sciter(rename = "fadeIn", duration)]
+    fade_in: f64,
+    #[sciter(default)]
+    margins: Vec<i32>,
+}
+
+#[derive(Debug, PartialEq, FromValue, IntoValue)]
+enum Shape {
+    Point,
+    Circle { radius: f64 },
+    Rect(f64, f64),
+}
+
+fn main() {
+    let theme = Theme { name: "dark".to_string(), background: 0xFF202020, fade_in: 0.25, margins: vec![] };
+    let v = Value::from(theme);
+    assert!(v["background"].is_color());
+    assert!(v["fadeIn"].is_duration());
+
+    let mut v = v;
+    v.set_item("margins", Value::new());
+    let theme = Theme::try_from_value(&v).unwrap();
+    assert_eq!(theme.margins, Vec::<i32>::new());
+
+    let v = Value::from(Shape::Circle { radius: 2.0 });
+    assert_eq!(Shape::from_value(&v), Some(Shape::Circle { radius: 2.0 }));
+    assert_eq!(Value::from(Shape::Point), Value::from("Point"));
+}
+```
+
+Enums can not be stored as arrays:
+
+```compile_fail
+#[macro_use] extern crate sciter_derive;
+extern crate sciter;
+
+#[derive(IntoValue)]
+#[sciter(array)]
+enum Shape {
+    Point,
+}
+
+fn main() {}
+```
+
+*/
+
+#![doc(html_logo_url = "https://sciter.com/screenshots/slide-sciter-osx.png",
+       html_favicon_url = "https://sciter.com/wp-content/themes/sciter/!images/favicon.ico")]
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as Tokens};
+use syn::{Data, DeriveInput, Fields, Ident, Index};
+
+
+/// Implements `sciter::FromValue` for a struct or an enum.
+#[proc_macro_derive(FromValue, attributes(sciter))]
+pub fn derive_from_value(input: TokenStream) -> TokenStream {
+	let input = syn::parse_macro_input!(input as DeriveInput);
+	expand_from_value(&input).unwrap_or_else(compile_errors).into()
+}
+
+/// Implements `From<T> for sciter::Value` for a struct or an enum.
+#[proc_macro_derive(IntoValue, attributes(sciter))]
+pub fn derive_into_value(input: TokenStream) -> TokenStream {
+	let input = syn::parse_macro_input!(input as DeriveInput);
+	expand_into_value(&input).unwrap_or_else(compile_errors).into()
+}
+
+/// `compile_error!` invocations for `e`.
+///
+/// `syn::Error::into_compile_error` refers to `::core`, which does not resolve in 2015 edition crates.
+fn compile_errors(e: syn::Error) -> Tokens {
+	let errors = e.into_iter().map(|e| {
+		let message = e.to_string();
+		quote_spanned!(e.span()=> compile_error!(#message);)
+	});
+	quote!(#(#errors)*)
+}
+
+
+
+/// `#[sciter(...)]` attributes of a container, a variant or a field.
+#[derive(Default)]
+struct Attrs {
+	array: bool,
+	rename: Option<String>,
+	default: Option<Tokens>,
+	color: bool,
+	duration: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Place {
+	Container,
+	Enum,
+	Variant,
+	Field,
+}
+
+impl Attrs {
+	fn parse(attrs: &[syn::Attribute], place: Place) -> syn::Result<Attrs> {
+		let mut me = Attrs::default();
+		for attr in attrs.iter().filter(|a| a.path().is_ident("sciter")) {
+			attr.parse_nested_meta(|meta| {
+				let allowed = match place {
+					Place::Container => meta.path.is_ident("array"),
+					Place::Enum => false,
+					Place::Variant => meta.path.is_ident("rename"),
+					Place::Field => ["rename", "default", "color", "duration"].iter().any(|name| meta.path.is_ident(name)),
+				};
+				if place == Place::Enum && meta.path.is_ident("array") {
+					return Err(meta.error("`array` is not supported for enums"));
+				}
+				if !allowed {
+					return Err(meta.error("unsupported `sciter` attribute here"));
+				}
+				if meta.path.is_ident("array") {
+					me.array = true;
+				} else if meta.path.is_ident("rename") {
+					let name: syn::LitStr = meta.value()?.parse()?;
+					me.rename = Some(name.value());
+				} else if meta.path.is_ident("default") {
+					me.default = Some(if meta.input.peek(syn::Token![=]) {
+						let path: syn::LitStr = meta.value()?.parse()?;
+						let path: syn::ExprPath = path.parse()?;
+						quote!(#path())
+					} else {
+						quote!(::std::default::Default::default())
+					});
+				} else if meta.path.is_ident("color") {
+					me.color = true;
+				} else if meta.path.is_ident("duration") {
+					me.duration = true;
+				}
+				Ok(())
+			})?;
+		}
+		if me.color && me.duration {
+			return Err(syn::Error::new(Span::call_site(), "`color` and `duration` can not be combined"));
+		}
+		Ok(me)
+	}
+}
+
+/// A field with its attributes.
+struct Field {
+	/// Binding name used in the generated code.
+	binding: Ident,
+	/// Struct member (name or index).
+	member: syn::Member,
+	/// Map key.
+	key: String,
+	ty: syn::Type,
+	attrs: Attrs,
+}
+
+/// Shape of the fields of a struct or a variant.
+enum Shape {
+	Unit,
+	Newtype(Box<Field>),
+	Map(Vec<Field>),
+	Array(Vec<Field>),
+}
+
+impl Shape {
+	fn parse(fields: &Fields, as_array: bool) -> syn::Result<Shape> {
+		let mut list = Vec::new();
+		for (i, f) in fields.iter().enumerate() {
+			let attrs = Attrs::parse(&f.attrs, Place::Field)?;
+			let (binding, member, name) = match f.ident {
+				Some(ref ident) => (ident.clone(), syn::Member::Named(ident.clone()), ident.to_string()),
+				None => (format_ident!("__field{}", i), syn::Member::Unnamed(Index::from(i)), i.to_string()),
+			};
+			let key = attrs.rename.clone().unwrap_or_else(|| name.trim_start_matches("r#").to_string());
+			list.push(Field { binding, member, key, ty: f.ty.clone(), attrs });
+		}
+		let shape = match *fields {
+			Fields::Unit => Shape::Unit,
+			Fields::Unnamed(_) if list.len() == 1 => Shape::Newtype(Box::new(list.pop().unwrap())),
+			Fields::Unnamed(_) => Shape::Array(list),
+			Fields::Named(_) if as_array => Shape::Array(list),
+			Fields::Named(_) => Shape::Map(list),
+		};
+		Ok(shape)
+	}
+
+	fn fields(&self) -> Vec<&Field> {
+		match *self {
+			Shape::Unit => Vec::new(),
+			Shape::Newtype(ref f) => vec![&**f],
+			Shape::Map(ref list) | Shape::Array(ref list) => list.iter().collect(),
+		}
+	}
+
+	/// Pattern binding all fields of `path`.
+	fn pattern(&self, path: &Tokens) -> Tokens {
+		let bindings = self.fields().into_iter().map(|f| {
+			let binding = &f.binding;
+			match f.member {
+				syn::Member::Named(_) => quote!(#binding),
+				syn::Member::Unnamed(ref index) => quote!(#index: #binding),
+			}
+		});
+		match *self {
+			Shape::Unit => quote!(#path),
+			_ => quote!(#path { #(#bindings),* }),
+		}
+	}
+
+	/// Expression converting `src: &Value` to `path`, evaluates to `Result<_, ConversionError>`.
+	fn extract(&self, path: &Tokens, src: &Tokens) -> Tokens {
+		match *self {
+			Shape::Unit => quote! {
+				if #src.is_null() || #src.is_undefined() {
+					Ok(#path)
+				} else {
+					Err(::sciter::value::ConversionError::new("null", #src))
+				}
+			},
+			Shape::Newtype(ref f) => {
+				let member = &f.member;
+				let item = field_from_value(f, quote!(#src.clone()), quote!(e));
+				catching(quote!(#path { #member: #item }))
+			},
+			Shape::Map(ref list) => {
+				let members = list.iter().map(|f| &f.member);
+				let items = list.iter().map(|f| {
+					let key = &f.key;
+					field_from_value(f, quote!(#src.get_item(#key)), quote!(e.at_key(#key)))
+				});
+				let convert = catching(quote!(#path { #(#members: #items),* }));
+				quote! {
+					if #src.is_map() || #src.is_object_map() || #src.is_object_class() {
+						#convert
+					} else {
+						Err(::sciter::value::ConversionError::new("map", #src))
+					}
+				}
+			},
+			Shape::Array(ref list) => {
+				let members = list.iter().map(|f| &f.member);
+				let items = list.iter().enumerate().map(|(i, f)| {
+					field_from_value(f, quote!(#src.get(#i)), quote!(e.at_index(#i)))
+				});
+				let convert = catching(quote!(#path { #(#members: #items),* }));
+				quote! {
+					if #src.is_array() || #src.is_object_array() {
+						#convert
+					} else {
+						Err(::sciter::value::ConversionError::new("array", #src))
+					}
+				}
+			},
+		}
+	}
+
+	/// Expression converting the bound fields to `Value`.
+	fn to_value(&self) -> Tokens {
+		match *self {
+			Shape::Unit => quote!(::sciter::Value::null()),
+			Shape::Newtype(ref f) => field_into_value(f),
+			Shape::Map(ref list) => {
+				let keys = list.iter().map(|f| &f.key);
+				let items = list.iter().map(field_into_value);
+				quote! {{
+					let mut __sciter_map = ::sciter::Value::map();
+					#( __sciter_map.set_item(#keys, #items); )*
+					__sciter_map
+				}}
+			},
+			Shape::Array(ref list) => {
+				let count = list.len();
+				let indices = 0..count;
+				let items = list.iter().map(field_into_value);
+				quote! {{
+					let mut __sciter_array = ::sciter::Value::array(#count);
+					#( __sciter_array.set(#indices, #items); )*
+					__sciter_array
+				}}
+			},
+		}
+	}
+}
+
+/// Evaluates the `construct` expression which uses `?`, to `Result<_, ConversionError>`.
+fn catching(construct: Tokens) -> Tokens {
+	quote! {
+		(|| -> ::std::result::Result<_, ::sciter::value::ConversionError> { Ok(#construct) })()
+	}
+}
+
+/// Field conversion, `?` returns the error with `at` applied to `e`.
+fn field_from_value(f: &Field, item: Tokens, at: Tokens) -> Tokens {
+	let ty = &f.ty;
+	let convert = if f.attrs.color {
+		quote!(item.to_color().ok_or_else(|| ::sciter::value::ConversionError::new("color", &item)))
+	} else if f.attrs.duration {
+		quote!(item.to_duration().ok_or_else(|| ::sciter::value::ConversionError::new("duration", &item)))
+	} else {
+		quote!(<#ty as ::sciter::FromValue>::try_from_value(&item))
+	};
+	let convert = quote!( #convert.map_err(|e| #at)? );
+	match f.attrs.default {
+		Some(ref default) => quote! {{
+			let item = #item;
+			if item.is_undefined() { #default } else { #convert }
+		}},
+		None => quote! {{
+			let item = #item;
+			#convert
+		}},
+	}
+}
+
+fn field_into_value(f: &Field) -> Tokens {
+	let binding = &f.binding;
+	if f.attrs.color {
+		quote!(::sciter::Value::color(#binding))
+	} else if f.attrs.duration {
+		quote!(::sciter::Value::duration(#binding))
+	} else {
+		quote!(::std::convert::Into::<::sciter::Value>::into(#binding))
+	}
+}
+
+/// Generics with `bound` added to every type parameter.
+fn bounded(input: &DeriveInput, bound: Tokens) -> syn::Generics {
+	let mut generics = input.generics.clone();
+	for param in generics.type_params_mut() {
+		param.bounds.push(syn::parse_quote!(#bound));
+	}
+	generics
+}
+
+/// Enum variants with their names and shapes.
+fn variants(data: &syn::DataEnum) -> syn::Result<Vec<(Ident, String, Shape)>> {
+	data.variants.iter().map(|v| {
+		let attrs = Attrs::parse(&v.attrs, Place::Variant)?;
+		let name = attrs.rename.unwrap_or_else(|| v.ident.to_string());
+		Ok((v.ident.clone(), name, Shape::parse(&v.fields, false)?))
+	}).collect()
+}
+
+/// Container attributes, enums have none.
+fn container_attrs(input: &DeriveInput) -> syn::Result<Attrs> {
+	let place = match input.data {
+		Data::Enum(_) => Place::Enum,
+		_ => Place::Container,
+	};
+	Attrs::parse(&input.attrs, place)
+}
+
+
+fn expand_from_value(input: &DeriveInput) -> syn::Result<Tokens> {
+	let attrs = container_attrs(input)?;
+	let ident = &input.ident;
+	let src = quote!(v);
+
+	let body = match input.data {
+		Data::Struct(ref data) => {
+			Shape::parse(&data.fields, attrs.array)?.extract(&quote!(#ident), &src)
+		},
+		Data::Enum(ref data) => {
+			let variants = variants(data)?;
+			let expected = format!("variant of `{}`", ident);
+			let units = variants.iter().filter(|v| matches!(v.2, Shape::Unit)).map(|(variant, name, _)| {
+				quote!(#name => return Ok(#ident::#variant),)
+			});
+			let tagged = variants.iter().filter(|v| !matches!(v.2, Shape::Unit)).map(|(variant, name, shape)| {
+				let convert = shape.extract(&quote!(#ident::#variant), &quote!(data));
+				quote!(#name => return #convert.map_err(|e| e.at_key(#name)),)
+			});
+			quote! {
+				if let Some(name) = v.as_string() {
+					match name.as_str() {
+						#(#units)*
+						_ => {},
+					}
+				} else if (v.is_map() || v.is_object_map()) && v.len() == 1 {
+					if let Some(name) = v.key_at(0).as_string() {
+						let data = &v.get(0);
+						match name.as_str() {
+							#(#tagged)*
+							_ => {},
+						}
+					}
+				}
+				Err(::sciter::value::ConversionError::new(#expected, v))
+			}
+		},
+		Data::Union(_) => return Err(syn::Error::new(Span::call_site(), "unions are not supported")),
+	};
+
+	let generics = bounded(input, quote!(::sciter::FromValue));
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	Ok(quote! {
+		impl #impl_generics ::sciter::FromValue for #ident #ty_generics #where_clause {
+			fn from_value(v: &::sciter::Value) -> ::std::option::Option<Self> {
+				Self::try_from_value(v).ok()
+			}
+
+			#[allow(clippy::redundant_closure_call)]
+			fn try_from_value(v: &::sciter::Value) -> ::std::result::Result<Self, ::sciter::value::ConversionError> {
+				#body
+			}
+		}
+	})
+}
+
+fn expand_into_value(input: &DeriveInput) -> syn::Result<Tokens> {
+	let attrs = container_attrs(input)?;
+	let ident = &input.ident;
+
+	let body = match input.data {
+		Data::Struct(ref data) => {
+			let shape = Shape::parse(&data.fields, attrs.array)?;
+			let pattern = shape.pattern(&quote!(#ident));
+			let value = shape.to_value();
+			quote! {
+				let #pattern = val;
+				#value
+			}
+		},
+		Data::Enum(ref data) => {
+			let arms = variants(data)?.into_iter().map(|(variant, name, shape)| {
+				let pattern = shape.pattern(&quote!(#ident::#variant));
+				match shape {
+					Shape::Unit => quote!(#pattern => ::sciter::Value::from(#name),),
+					_ => {
+						let value = shape.to_value();
+						quote! {
+							#pattern => {
+								let mut __sciter_map = ::sciter::Value::map();
+								__sciter_map.set_item(#name, #value);
+								__sciter_map
+							},
+						}
+					},
+				}
+			});
+			quote! {
+				match val {
+					#(#arms)*
+				}
+			}
+		},
+		Data::Union(_) => return Err(syn::Error::new(Span::call_site(), "unions are not supported")),
+	};
+
+	let generics = bounded(input, quote!(::std::convert::Into<::sciter::Value>));
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	Ok(quote! {
+		impl #impl_generics ::std::convert::From<#ident #ty_generics> for ::sciter::Value #where_clause {
+			#[allow(unused_variables)]
+			fn from(val: #ident #ty_generics) -> Self {
+				#body
+			}
+		}
+	})
+}
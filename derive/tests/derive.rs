@@ -0,0 +1,201 @@
+#[macro_use]
+extern crate sciter_derive;
+#[macro_use]
+extern crate sciter;
+
+use sciter::{FromValue, Value};
+
+
+#[derive(Debug, PartialEq, FromValue, IntoValue)]
+struct Point {
+	x: i32,
+	y: i32,
+}
+
+#[derive(Debug, PartialEq, FromValue, IntoValue)]
+#[sciter(array)]
+struct Size {
+	width: f64,
+	height: f64,
+}
+
+#[derive(Debug, PartialEq, FromValue, IntoValue)]
+struct Pair(String, Option<i32>);
+
+#[derive(Debug, PartialEq, FromValue, IntoValue)]
+struct Id(u32);
+
+#[derive(Debug, PartialEq, FromValue, IntoValue)]
+struct Empty;
+
+fn default_opacity() -> f64 {
+	1.0
+}
+
+#[derive(Debug, PartialEq, FromValue, IntoValue)]
+struct Style {
+	#[sciter(rename = "fontSize")]
+	font_size: u8,
+	#[sciter(color)]
+	color: u32,
+	#[sciter(duration)]
+	delay: f64,
+	#[sciter(default)]
+	tags: Vec<String>,
+	#[sciter(default = "default_opacity")]
+	opacity: f64,
+	origin: Point,
+}
+
+#[derive(Debug, PartialEq, FromValue, IntoValue)]
+enum Command {
+	Stop,
+	#[sciter(rename = "go")]
+	Go(Point),
+	Resize(f64, f64),
+	Paint { #[sciter(color)] color: u32, area: Size },
+}
+
+#[derive(Debug, PartialEq, FromValue, IntoValue)]
+struct Wrapper<T> {
+	inner: T,
+}
+
+#[derive(Debug, PartialEq, FromValue, IntoValue)]
+struct Shadowing {
+	map: i32,
+	array: Vec<i32>,
+}
+
+#[derive(Debug, PartialEq, FromValue, IntoValue)]
+#[sciter(array)]
+struct ShadowingArray {
+	map: i32,
+	array: i32,
+}
+
+#[derive(Debug, PartialEq, FromValue, IntoValue)]
+enum ShadowingEnum {
+	Map { map: i32 },
+	Array(i32, String),
+}
+
+
+#[test]
+fn structs_work() {
+	let v = Value::from(Point { x: 1, y: 2 });
+	assert!(v.is_map());
+	assert_eq!(v["x"], Value::from(1));
+	assert_eq!(Point::from_value(&v), Some(Point { x: 1, y: 2 }));
+
+	let v = Value::from(Size { width: 1.5, height: 2.0 });
+	assert!(v.is_array());
+	assert_eq!(v.len(), 2);
+	assert_eq!(Size::from_value(&v), Some(Size { width: 1.5, height: 2.0 }));
+
+	let v = Value::from(Pair("a".to_string(), None));
+	assert!(v.is_array());
+	assert!(v[1].is_null());
+	assert_eq!(Pair::from_value(&v), Some(Pair("a".to_string(), None)));
+
+	let v = Value::from(Id(7));
+	assert_eq!(v, Value::from(7));
+	assert_eq!(Id::from_value(&v), Some(Id(7)));
+
+	let v = Value::from(Empty);
+	assert!(v.is_null());
+	assert_eq!(Empty::from_value(&v), Some(Empty));
+
+	let v = Value::from(Wrapper { inner: vec![1, 2] });
+	assert_eq!(Wrapper::<Vec<i32>>::from_value(&v), Some(Wrapper { inner: vec![1, 2] }));
+}
+
+#[test]
+fn field_names_do_not_clash() {
+	let v = Value::from(Shadowing { map: 1, array: vec![2] });
+	assert_eq!(v["map"], Value::from(1));
+	assert_eq!(Shadowing::from_value(&v), Some(Shadowing { map: 1, array: vec![2] }));
+
+	let v = Value::from(ShadowingArray { map: 1, array: 2 });
+	assert_eq!(v, varray![1, 2]);
+	assert_eq!(ShadowingArray::from_value(&v), Some(ShadowingArray { map: 1, array: 2 }));
+
+	let v = Value::from(ShadowingEnum::Map { map: 3 });
+	assert_eq!(v["Map"]["map"], Value::from(3));
+	assert_eq!(ShadowingEnum::from_value(&v), Some(ShadowingEnum::Map { map: 3 }));
+
+	let v = Value::from(ShadowingEnum::Array(4, "a".to_string()));
+	assert_eq!(ShadowingEnum::from_value(&v), Some(ShadowingEnum::Array(4, "a".to_string())));
+}
+
+#[test]
+fn attributes_work() {
+	let style = Style {
+		font_size: 12,
+		color: 0xFF00_00FF,
+		delay: 0.5,
+		tags: vec!["bold".to_string()],
+		opacity: 0.5,
+		origin: Point { x: 0, y: 0 },
+	};
+	let v = Value::from(style);
+	assert_eq!(v["fontSize"], Value::from(12));
+	assert!(v["color"].is_color());
+	assert!(v["delay"].is_duration());
+	assert_eq!(v["delay"].to_duration(), Some(0.5));
+
+	let style = Style::try_from_value(&v).unwrap();
+	assert_eq!(style.color, 0xFF00_00FF);
+	assert_eq!(style.tags, vec!["bold".to_string()]);
+
+	let mut v = v;
+	v.set_item("tags", Value::new());
+	v.set_item("opacity", Value::new());
+	let style = Style::try_from_value(&v).unwrap();
+	assert!(style.tags.is_empty());
+	assert_eq!(style.opacity, 1.0);
+}
+
+#[test]
+fn enums_work() {
+	let v = Value::from(Command::Stop);
+	assert_eq!(v, Value::from("Stop"));
+	assert_eq!(Command::from_value(&v), Some(Command::Stop));
+	assert_eq!(Command::from_value(&Value::symbol("Stop")), Some(Command::Stop));
+
+	let v = Value::from(Command::Go(Point { x: 3, y: 4 }));
+	assert_eq!(v.len(), 1);
+	assert_eq!(v["go"]["y"], Value::from(4));
+	assert_eq!(Command::from_value(&v), Some(Command::Go(Point { x: 3, y: 4 })));
+
+	let v = Value::from(Command::Resize(1.0, 2.0));
+	assert!(v["Resize"].is_array());
+	assert_eq!(Command::from_value(&v), Some(Command::Resize(1.0, 2.0)));
+
+	let paint = Command::Paint { color: 0xFF, area: Size { width: 1.0, height: 1.0 } };
+	let v = Value::from(paint);
+	assert!(v["Paint"]["color"].is_color());
+	assert_eq!(Command::from_value(&v), Some(Command::Paint { color: 0xFF, area: Size { width: 1.0, height: 1.0 } }));
+}
+
+#[test]
+fn errors_work() {
+	let v = vmap! { "x" => 1, "y" => "2" };
+	let e = Point::try_from_value(&v).unwrap_err();
+	assert_eq!(e.path(), "y");
+	assert_eq!(e.expected(), "i32");
+
+	let e = Point::try_from_value(&Value::from(1)).unwrap_err();
+	assert_eq!(e.expected(), "map");
+
+	let v = vmap! { "go" => vmap! { "x" => 1 } };
+	let e = Command::try_from_value(&v).unwrap_err();
+	assert_eq!(e.path(), "go.y");
+
+	let e = Command::try_from_value(&Value::from("Jump")).unwrap_err();
+	assert_eq!(e.expected(), "variant of `Command`");
+
+	let v = vmap! { "Resize" => varray![1.0, "x"] };
+	let e = Command::try_from_value(&v).unwrap_err();
+	assert_eq!(e.path(), "Resize[1]");
+}
@@ -0,0 +1,240 @@
+/// Adapters for Sciter-specific value types.
+///
+/// Each adapter serializes its value as a newtype struct with a reserved name,
+/// which is turned into the typed Sciter value by our serializer
+/// and is just the plain inner value for other serializers.
+use std::time::{Duration as StdDuration, SystemTime, UNIX_EPOCH};
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use error::{Error, Result};
+use sciter::value::{self, Value};
+
+
+const COLOR: &str = "$sciter::color";
+const DURATION: &str = "$sciter::duration";
+const ANGLE: &str = "$sciter::angle";
+const DATE: &str = "$sciter::date";
+const CURRENCY: &str = "$sciter::currency";
+const LENGTH: &str = "$sciter::length";
+const RANGE: &str = "$sciter::range";
+
+
+/// Checks whether the newtype struct name is one of the adapters.
+pub(crate) fn is_adapter(name: &str) -> bool {
+	name.starts_with("$sciter::")
+}
+
+/// Converts the plain form of the adapter into the typed Sciter value.
+pub(crate) fn to_typed(name: &str, plain: Value) -> Result<Value> {
+	let typed = match name {
+		COLOR => to_number(&plain).and_then(to_color).map(Value::color),
+		DURATION => to_number(&plain).map(Value::duration),
+		ANGLE => to_number(&plain).map(Value::angle),
+		DATE => match to_number(&plain) {
+			Some(ms) => return from_millis(ms).map(|t| Value::date(t, true)),
+			None => None,
+		},
+		CURRENCY => plain.as_string().and_then(|s| s.parse().ok()).map(Value::currency),
+		LENGTH => plain.as_string().and_then(|s| s.parse().ok()).map(Value::length),
+		RANGE => to_range(&plain).map(Value::range),
+		_ => None,
+	};
+	typed.ok_or_else(|| Error::expected(&name[9..], &plain))
+}
+
+/// Converts the typed Sciter value into the plain form of the adapter.
+///
+/// Returns `None` if the value is not typed (e.g. it is already a plain value).
+pub(crate) fn to_plain(name: &str, typed: &Value) -> Option<Value> {
+	match name {
+		COLOR => typed.to_color().map(|v| Value::from(f64::from(v))),
+		DURATION => typed.to_duration().map(Value::from),
+		ANGLE => typed.to_angle().map(Value::from),
+		DATE => typed.to_date().map(|t| Value::from(to_millis(t))),
+		CURRENCY => typed.to_currency().map(|v| Value::from(v.to_string())),
+		LENGTH => typed.to_length().map(|v| Value::from(v.to_string())),
+		RANGE => typed.to_range().map(|v| {
+			let mut plain = Value::array(0);
+			plain.push(v.start);
+			plain.push(v.end);
+			plain
+		}),
+		_ => None,
+	}
+}
+
+fn to_number(plain: &Value) -> Option<f64> {
+	plain.to_float().or_else(|| plain.to_int().map(f64::from))
+}
+
+// Integers in the `u32` range only.
+fn to_color(v: f64) -> Option<u32> {
+	if v.trunc() == v && v >= 0.0 && v <= f64::from(u32::MAX) {
+		Some(v as u32)
+	} else {
+		None
+	}
+}
+
+fn to_range(plain: &Value) -> Option<value::Range> {
+	if plain.is_array() && plain.len() == 2 {
+		Some(value::Range::new(plain[0].to_int()?, plain[1].to_int()?))
+	} else {
+		None
+	}
+}
+
+// JavaScript timestamp: milliseconds since the Unix epoch.
+fn to_millis(t: SystemTime) -> f64 {
+	fn millis(d: StdDuration) -> f64 {
+		d.as_secs() as f64 * 1000.0 + f64::from(d.subsec_nanos()) / 1_000_000.0
+	}
+	match t.duration_since(UNIX_EPOCH) {
+		Ok(d) => millis(d),
+		Err(e) => -millis(e.duration()),
+	}
+}
+
+// Fails on non-finite timestamps and on those out of the `SystemTime` range.
+fn from_millis(ms: f64) -> Result<SystemTime> {
+	if !ms.is_finite() {
+		return Err(Error::expected("finite timestamp", &Value::from(ms)));
+	}
+	let overflow = || Error::Overflow { value: ms.to_string(), target: "SystemTime" };
+	let whole = ms.abs().trunc();
+	// `u64::MAX as f64` is 2^64, `as u64` would saturate at it
+	if whole >= u64::MAX as f64 {
+		return Err(overflow());
+	}
+	let nanos = ((ms.abs() - whole) * 1_000_000.0).round() as u64;
+	let d = StdDuration::from_millis(whole as u64).checked_add(StdDuration::from_nanos(nanos));
+	let t = d.and_then(|d| if ms < 0.0 { UNIX_EPOCH.checked_sub(d) } else { UNIX_EPOCH.checked_add(d) });
+	t.ok_or_else(overflow)
+}
+
+
+// Deserializes the inner value of the adapter.
+struct Inner<T>(::std::marker::PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for Inner<T> {
+	type Value = T;
+
+	fn expecting(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+		f.write_str("a Sciter value")
+	}
+
+	fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> ::std::result::Result<T, D::Error> {
+		T::deserialize(deserializer)
+	}
+}
+
+fn deserialize_inner<'de, D: Deserializer<'de>, T: Deserialize<'de>>(name: &'static str, deserializer: D) -> ::std::result::Result<T, D::Error> {
+	deserializer.deserialize_newtype_struct(name, Inner(::std::marker::PhantomData))
+}
+
+
+macro_rules! adapter {
+	($(#[$mdoc:meta])* mod $module:ident, $(#[$tdoc:meta])* struct $wrapper:ident, $name:ident, $plain:ty,
+		$target:ty, $ser:expr, $de:expr) => {
+
+		$(#[$mdoc])*
+		pub mod $module {
+			use super::*;
+
+			/// Serializes the value as the typed Sciter value.
+			pub fn serialize<S: Serializer>(value: &$target, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+				let plain: $plain = $ser(value);
+				serializer.serialize_newtype_struct($name, &plain)
+			}
+
+			/// Deserializes the value from either the typed Sciter value or its plain form.
+			pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> ::std::result::Result<$target, D::Error> {
+				let plain: $plain = deserialize_inner($name, deserializer)?;
+				$de(plain)
+			}
+		}
+
+		$(#[$tdoc])*
+		#[derive(Debug, Clone, Copy, PartialEq)]
+		pub struct $wrapper(pub $target);
+
+		impl Serialize for $wrapper {
+			fn serialize<S: Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+				$module::serialize(&self.0, serializer)
+			}
+		}
+
+		impl<'de> Deserialize<'de> for $wrapper {
+			fn deserialize<D: Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+				$module::deserialize(deserializer).map($wrapper)
+			}
+		}
+
+		impl From<$target> for $wrapper {
+			fn from(value: $target) -> Self {
+				$wrapper(value)
+			}
+		}
+	};
+}
+
+adapter! {
+	/// `u32` as [`T_COLOR`](https://docs.rs/sciter-rs/*/sciter/value/enum.VALUE_TYPE.html), the plain form is an integer.
+	mod color,
+	/// `u32` stored as Sciter color.
+	struct Color, COLOR, u32, u32,
+	|v: &u32| *v, Ok
+}
+
+adapter! {
+	/// `f64` seconds as `T_DURATION`, the plain form is a float.
+	mod duration,
+	/// `f64` seconds stored as Sciter duration.
+	struct Duration, DURATION, f64, f64,
+	|v: &f64| *v, Ok
+}
+
+adapter! {
+	/// `f64` radians as `T_ANGLE`, the plain form is a float.
+	mod angle,
+	/// `f64` radians stored as Sciter angle.
+	struct Angle, ANGLE, f64, f64,
+	|v: &f64| *v, Ok
+}
+
+adapter! {
+	/// `SystemTime` as `T_DATE`, the plain form is a JavaScript timestamp (milliseconds since the Unix epoch).
+	mod date,
+	/// `SystemTime` stored as Sciter date.
+	struct Date, DATE, f64, SystemTime,
+	|v: &SystemTime| to_millis(*v), |ms| from_millis(ms).map_err(de::Error::custom)
+}
+
+adapter! {
+	/// [`Currency`](https://docs.rs/sciter-rs/*/sciter/value/struct.Currency.html) as `T_CURRENCY`,
+	/// the plain form is a decimal string like `"12.3400"`.
+	mod currency,
+	/// `sciter::value::Currency` stored as Sciter currency.
+	struct Currency, CURRENCY, String, value::Currency,
+	|v: &value::Currency| v.to_string(), |s: String| s.parse().map_err(de::Error::custom)
+}
+
+adapter! {
+	/// [`Length`](https://docs.rs/sciter-rs/*/sciter/value/enum.Length.html) as `T_LENGTH`,
+	/// the plain form is a CSS string like `"12px"`.
+	mod length,
+	/// `sciter::value::Length` stored as Sciter length.
+	struct Length, LENGTH, String, value::Length,
+	|v: &value::Length| v.to_string(), |s: String| s.parse().map_err(de::Error::custom)
+}
+
+adapter! {
+	/// [`Range`](https://docs.rs/sciter-rs/*/sciter/value/struct.Range.html) as `T_RANGE`,
+	/// the plain form is a `[start, end]` array.
+	mod range,
+	/// `sciter::value::Range` stored as Sciter range.
+	struct Range, RANGE, (i32, i32), value::Range,
+	|v: &value::Range| (v.start, v.end), |(start, end)| Ok(value::Range::new(start, end))
+}
@@ -0,0 +1,83 @@
+/// Script calls with serializable arguments.
+use serde::{Serialize, de::DeserializeOwned};
+
+use de::from_value;
+use error::Result;
+use ser::to_value;
+use sciter::{Element, Host, Value};
+
+
+/// Serialize the given data into the argument list of a script call.
+///
+/// A sequence or a tuple is spread into separate arguments, a unit (or `None`) means no arguments
+/// and any other value is passed as a single argument.
+/// Wrap a sequence into a 1-tuple like `(&items,)` to pass it as one array argument.
+pub fn to_args<T: ?Sized + Serialize>(args: &T) -> Result<Vec<Value>> {
+	let v = to_value(args)?;
+	let args = if v.is_array() {
+		v.values().collect()
+	} else if v.is_null() || v.is_undefined() {
+		Vec::new()
+	} else {
+		vec![v]
+	};
+	Ok(args)
+}
+
+/// Script calls with arguments and result converted via Serde.
+///
+/// It is a counterpart of [`Host::call`](https://docs.rs/sciter-rs/*/sciter/host/struct.Host.html#method.call)
+/// for types which implement `Serialize` and `Deserialize` rather than `Into<Value>` and `FromValue`.
+///
+/// ```rust,no_run
+/// # #![doc(test(no_crate_inject))]
+/// #[macro_use]
+/// extern crate serde_derive;
+/// extern crate sciter;
+/// extern crate sciter_serde;
+///
+/// use sciter_serde::ScriptCall;
+///
+/// #[derive(Serialize)]
+/// struct Query { text: String, limit: u32 }
+///
+/// #[derive(Deserialize)]
+/// struct Found { total: u32, items: Vec<String> }
+///
+/// fn main() {
+/// 	let host = sciter::Host::attach(0 as sciter::types::HWINDOW);
+/// 	let query = Query { text: "rust".to_string(), limit: 10 };
+/// 	let found: Found = host.call_serde("app.search", &(&query,)).unwrap();
+/// 	println!("{} of {}", found.items.len(), found.total);
+/// }
+/// ```
+pub trait ScriptCall {
+	/// Call a script function with the arguments serialized via [`to_args`](fn.to_args.html)
+	/// and deserialize its result.
+	fn call_serde<A, R>(&self, name: &str, args: &A) -> Result<R>
+	where
+		A: ?Sized + Serialize,
+		R: DeserializeOwned;
+}
+
+impl ScriptCall for Host {
+	fn call_serde<A, R>(&self, name: &str, args: &A) -> Result<R>
+	where
+		A: ?Sized + Serialize,
+		R: DeserializeOwned,
+	{
+		let rv = self.call_function(name, &to_args(args)?)?;
+		from_value(&rv)
+	}
+}
+
+impl ScriptCall for Element {
+	fn call_serde<A, R>(&self, name: &str, args: &A) -> Result<R>
+	where
+		A: ?Sized + Serialize,
+		R: DeserializeOwned,
+	{
+		let rv = self.call_function(name, &to_args(args)?)?;
+		from_value(&rv)
+	}
+}
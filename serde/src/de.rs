@@ -1,7 +1,11 @@
 /// Deserialization.
+use std::borrow::Cow;
+
 use serde::de::{self, Deserialize, Visitor};
 
+use adapters;
 use error::{Error, Result};
+use options::{EnumRepr, Options};
 use sciter::{Value};
 
 
@@ -9,26 +13,78 @@ use sciter::{Value};
 pub fn from_value<'a, T>(input: &'a Value) -> Result<T>
 	where T: Deserialize<'a>
 {
-	let p = Deserializer::from_value(input.clone());
+	from_value_with(input, &Options::default())
+}
+
+/// Deserializes a Sciter value to the specific Rust type using the specified options.
+pub fn from_value_with<'a, T>(input: &'a Value, options: &Options) -> Result<T>
+	where T: Deserialize<'a>
+{
+	let p = Deserializer::with_options(input, options);
 	T::deserialize(p)
 }
 
 
 /// Implementation of deserialization.
-pub struct Deserializer {
-	input: Value,
+///
+/// It walks the borrowed value without cloning it: nested values are obtained as shallow copies
+/// (Sciter arrays and maps are reference counted), byte arrays of the borrowed value are passed as is.
+/// Strings are stored as UTF-16 in Sciter, so they are always converted.
+///
+/// Only a top-level byte array can be borrowed (as `&'a [u8]`): nested values are temporary copies,
+/// so their bytes are passed as transient and a nested `#[serde(borrow)] &'a [u8]` field fails
+/// with "invalid type". Use `Cow<'a, [u8]>`, `Vec<u8>` or `serde_bytes::ByteBuf` for nested byte arrays.
+///
+/// Integers of any width are also accepted as decimal strings (e.g. `"42"` for `u8`),
+/// not only the 64-bit ones stored by [`Int64Repr::String`](enum.Int64Repr.html#variant.String).
+///
+/// Errors of nested values carry the path to the offending value, see [`Error::At`](enum.Error.html#variant.At).
+pub struct Deserializer<'a> {
+	input: Cow<'a, Value>,
+	options: Options,
 }
 
 
-impl<'de> Deserializer {
+impl<'de> Deserializer<'de> {
+
+	pub fn from_value(input: &'de Value) -> Self {
+		Self::with_options(input, &Options::default())
+	}
 
-	pub fn from_value(input: Value) -> Self {
-		Deserializer { input: input }
+	pub fn with_options(input: &'de Value, options: &Options) -> Self {
+		Deserializer { input: Cow::Borrowed(input), options: *options }
+	}
+
+	// Integers of every width can be stored as `i32`, as a whole `f64` or as a decimal string, see `Int64Repr`.
+	fn deserialize_integer<V: Visitor<'de>>(self, visitor: V, target: &'static str, min: i128, max: i128) -> Result<V::Value> {
+		use sciter::value::VALUE_TYPE;
+		let value = match self.input.get_type() {
+			VALUE_TYPE::T_INT => self.input.to_int().map(|v| v as i128),
+			VALUE_TYPE::T_FLOAT => self.input.to_float().filter(|v| v.fract() == 0.0).map(|v| v as i128),
+			VALUE_TYPE::T_STRING => self.input.as_string().and_then(|s| s.trim().parse::<i128>().ok()),
+			_ => None,
+		};
+		let value = match value {
+			Some(value) => value,
+			None => return Err(Error::expected("integer", &self.input)),
+		};
+		if value < min || value > max {
+			Err(Error::Overflow { value: value.to_string(), target: target })
+		} else if value < 0 {
+			visitor.visit_i64(value as i64)
+		} else {
+			visitor.visit_u64(value as u64)
+		}
+	}
+
+	// Deserializer of a nested value with the same options.
+	fn nested(&self, input: Value) -> Self {
+		Deserializer { input: Cow::Owned(input), options: self.options }
 	}
 }
 
 
-impl<'de, 'a> ::serde::de::Deserializer<'de> for Deserializer {
+impl<'de> ::serde::de::Deserializer<'de> for Deserializer<'de> {
 	type Error = Error;
 
 
@@ -40,10 +96,13 @@ impl<'de, 'a> ::serde::de::Deserializer<'de> for Deserializer {
 			VALUE_TYPE::T_BOOL => visitor.visit_bool(self.input.to_bool().unwrap()),
 			VALUE_TYPE::T_INT => visitor.visit_i32(self.input.to_int().unwrap()),
 			VALUE_TYPE::T_FLOAT => visitor.visit_f64(self.input.to_float().unwrap()),
-			VALUE_TYPE::T_STRING => visitor.visit_str(&self.input.as_string().unwrap()),
+			VALUE_TYPE::T_STRING => visitor.visit_string(self.input.as_string().unwrap()),
 			VALUE_TYPE::T_ARRAY => visitor.visit_seq(SeqAccess::new(self)),
 			VALUE_TYPE::T_MAP => self.deserialize_map(visitor),
-			VALUE_TYPE::T_BYTES => visitor.visit_bytes(self.input.as_bytes().unwrap()),
+			VALUE_TYPE::T_BYTES => match self.input {
+				Cow::Borrowed(input) => visitor.visit_borrowed_bytes(input.as_bytes().unwrap()),
+				Cow::Owned(ref input) => visitor.visit_bytes(input.as_bytes().unwrap()),
+			},
 			VALUE_TYPE::T_OBJECT => self.deserialize_map(visitor),
 			_ => Err(Error::UnsupportedType),
 		}
@@ -57,12 +116,44 @@ impl<'de, 'a> ::serde::de::Deserializer<'de> for Deserializer {
 		if let Some(v) = self.input.to_bool() {
 			visitor.visit_bool(v)
 		} else {
-			Err(Error::ExpectedType(format!("expected {:?}, given {:?}", "bool", self.input)))
+			Err(Error::expected("bool", &self.input))
 		}
 	}
 
 	forward_to_deserialize_any! {
-		i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes	byte_buf
+		f32 f64 char str string bytes	byte_buf
+	}
+
+	fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		self.deserialize_integer(visitor, "i8", i8::MIN as i128, i8::MAX as i128)
+	}
+
+	fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		self.deserialize_integer(visitor, "i16", i16::MIN as i128, i16::MAX as i128)
+	}
+
+	fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		self.deserialize_integer(visitor, "i32", i32::MIN as i128, i32::MAX as i128)
+	}
+
+	fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		self.deserialize_integer(visitor, "i64", i64::MIN as i128, i64::MAX as i128)
+	}
+
+	fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		self.deserialize_integer(visitor, "u8", 0, u8::MAX as i128)
+	}
+
+	fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		self.deserialize_integer(visitor, "u16", 0, u16::MAX as i128)
+	}
+
+	fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		self.deserialize_integer(visitor, "u32", 0, u32::MAX as i128)
+	}
+
+	fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		self.deserialize_integer(visitor, "u64", 0, u64::MAX as i128)
 	}
 
 	fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
@@ -77,7 +168,7 @@ impl<'de, 'a> ::serde::de::Deserializer<'de> for Deserializer {
 		if self.input.is_undefined() || self.input.is_null() {
 			visitor.visit_unit()
 		} else {
-			Err(Error::ExpectedType(format!("expected {:?}, given {:?}", "null", self.input)))
+			Err(Error::expected("null", &self.input))
 		}
 	}
 
@@ -85,17 +176,19 @@ impl<'de, 'a> ::serde::de::Deserializer<'de> for Deserializer {
 		self.deserialize_unit(visitor)
 	}
 
-	fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &str, visitor: V) -> Result<V::Value> {
-		visitor.visit_newtype_struct(self)
+	fn deserialize_newtype_struct<V: Visitor<'de>>(self, name: &str, visitor: V) -> Result<V::Value> {
+		// Sciter-specific values are passed in their plain form, see `adapters`.
+		match adapters::to_plain(name, &self.input) {
+			Some(plain) => visitor.visit_newtype_struct(self.nested(plain)),
+			None => visitor.visit_newtype_struct(self),
+		}
 	}
 
 	fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value>	{
 		if self.input.is_array() {
-			let it = self.input.values();
-			let sq = de::value::SeqDeserializer::new(it);
-			visitor.visit_seq(sq)
+			visitor.visit_seq(SeqAccess::new(self))
 		} else {
-			Err(Error::ExpectedType(format!("expected {:?}, given {:?}", "sequence", self.input)))
+			Err(Error::expected("array", &self.input))
 		}
 	}
 
@@ -109,11 +202,9 @@ impl<'de, 'a> ::serde::de::Deserializer<'de> for Deserializer {
 
 	fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
 		if self.input.is_map() {
-			let it = self.input.items().into_iter();
-			let sq = de::value::MapDeserializer::new(it);
-			visitor.visit_map(sq)
+			visitor.visit_map(SeqAccess::new(self))
 		} else {
-			Err(Error::ExpectedType(format!("expected {:?}, given {:?}", "map", self.input)))
+			Err(Error::expected("map", &self.input))
 		}
 	}
 
@@ -124,33 +215,55 @@ impl<'de, 'a> ::serde::de::Deserializer<'de> for Deserializer {
 
 	fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value>
 	{
-		self.deserialize_str(visitor)
+		// variants can be identified by index as well
+		match self.input.to_int() {
+			Some(index) if index >= 0 => visitor.visit_u64(index as u64),
+			_ => self.deserialize_str(visitor),
+		}
 	}
 
 	fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _fields: &'static [&'static str], visitor: V) -> Result<V::Value>
 	{
-		// it can be `"A"`, `{"T": u8}`, `{S: {"x": u8}}`
-		match (self.input.is_string(), self.input.is_map()) {
-
-			(true, _) => {
-				use self::de::IntoDeserializer;
-				visitor.visit_enum(self.input.as_string().unwrap().into_deserializer())
+		let (variant, content) = match self.options.enum_repr {
+			// it can be `"A"`, `{"N": u8}`, `{"S": {"x": u8}}`
+			EnumRepr::External => {
+				if self.input.is_map() && self.input.len() == 1 {
+					(self.input.key_at(0), self.input.get(0))
+				} else if self.input.is_string() || self.input.is_int() {
+					(self.input.clone().into_owned(), Value::new())
+				} else {
+					return Err(Error::expected("enum (as string or map)", &self.input));
+				}
 			},
 
-			(_, true) => {
-				visitor.visit_enum(SeqAccess::new(self))
+			// it can be `{"t": "A"}`, `{"t": "N", "c": u8}`
+			EnumRepr::Adjacent { tag, content } => {
+				let variant = if self.input.is_map() { self.input.get_item(tag) } else { Value::new() };
+				if variant.is_undefined() {
+					return Err(Error::expected(&format!("enum (as map with {:?} key)", tag), &self.input));
+				}
+				(variant, self.input.get_item(content))
 			},
-
-			_ => {
-				Err(Error::ExpectedType(format!("expected enum (as string or map), given {:?}", self.input)))
-			}
-		}
+		};
+		let name = variant.as_string().unwrap_or_else(|| variant.to_string());
+		visitor.visit_enum(EnumAccess {
+			variant: self.nested(variant),
+			content: VariantAccess { de: self.nested(content), name: name },
+		})
 	}
 }
 
 
 impl<'de> de::IntoDeserializer<'de, Error> for Value {
-	type Deserializer = Deserializer;
+	type Deserializer = Deserializer<'de>;
+
+	fn into_deserializer(self) -> Self::Deserializer {
+		Deserializer { input: Cow::Owned(self), options: Options::default() }
+	}
+}
+
+impl<'de> de::IntoDeserializer<'de, Error> for &'de Value {
+	type Deserializer = Deserializer<'de>;
 
 	fn into_deserializer(self) -> Self::Deserializer {
 		Deserializer::from_value(self)
@@ -159,26 +272,24 @@ impl<'de> de::IntoDeserializer<'de, Error> for Value {
 
 
 #[doc(hidden)]
-struct SeqAccess {
-	de: Deserializer,
+struct SeqAccess<'de> {
+	de: Deserializer<'de>,
 	pos: usize,
 	len: usize,
-	key: Option<Value>,
 }
 
-impl SeqAccess {
-	fn new(d: Deserializer) -> Self	{
+impl<'de> SeqAccess<'de> {
+	fn new(d: Deserializer<'de>) -> Self	{
 		let len = d.input.len();
 		SeqAccess {
 			de: d,
 			pos: 0,
 			len: len,
-			key: None,
 		}
 	}
 }
 
-impl<'de> de::SeqAccess<'de> for SeqAccess {
+impl<'de> de::SeqAccess<'de> for SeqAccess<'de> {
 	type Error = Error;
 
 	fn size_hint(&self) -> Option<usize> {
@@ -191,15 +302,15 @@ impl<'de> de::SeqAccess<'de> for SeqAccess {
 		if self.pos < self.len {
 			self.pos += 1;
 			let v = self.de.input.get(self.pos - 1);
-			let inner = Deserializer::from_value(v);
-			seed.deserialize(inner).map(Some)
+			let inner = self.de.nested(v);
+			seed.deserialize(inner).map(Some).map_err(|e| e.in_index(self.pos - 1))
 		} else {
 			Ok(None)
 		}
 	}
 }
 
-impl<'de> de::MapAccess<'de> for SeqAccess {
+impl<'de> de::MapAccess<'de> for SeqAccess<'de> {
 	type Error = Error;
 
 	fn size_hint(&self) -> Option<usize> {
@@ -212,7 +323,7 @@ impl<'de> de::MapAccess<'de> for SeqAccess {
 		if self.pos < self.len {
 			self.pos += 1;
 			let v = self.de.input.key_at(self.pos - 1);
-			let inner = Deserializer::from_value(v);
+			let inner = self.de.nested(v);
 			seed.deserialize(inner).map(Some)
 		} else {
 			Ok(None)
@@ -223,56 +334,68 @@ impl<'de> de::MapAccess<'de> for SeqAccess {
 		where V: de::DeserializeSeed<'de>
 	{
 		let v = self.de.input.get(self.pos - 1);
-		let inner = Deserializer::from_value(v);
-		seed.deserialize(inner)
+		let inner = self.de.nested(v);
+		seed.deserialize(inner).map_err(|e| {
+			let key = self.de.input.key_at(self.pos - 1);
+			e.in_field(&key.as_string().unwrap_or_else(|| key.to_string()))
+		})
 	}
 }
 
-impl<'de> de::EnumAccess<'de> for SeqAccess {
+#[doc(hidden)]
+struct EnumAccess<'de> {
+	variant: Deserializer<'de>,
+	content: VariantAccess<'de>,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumAccess<'de> {
 	type Error = Error;
-	type Variant = Self;
+	type Variant = VariantAccess<'de>;
 
-	fn variant_seed<V>(mut self, seed: V) -> Result<(V::Value, Self::Variant)>
+	fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant)>
 		where V: de::DeserializeSeed<'de>
 	{
-		// `{ "N": ... }`
-		// Here I suppose to deserialize the variant key.
-		let v = self.de.input.key_at(0);
-		self.key = Some(v.clone());
-		let vkey = seed.deserialize( Deserializer::from_value(v) )?;
-		Ok((vkey, self))
+		// the variant identifier: `"N"` or its index
+		let vkey = seed.deserialize(self.variant)?;
+		Ok((vkey, self.content))
 	}
 }
 
-impl<'de> de::VariantAccess<'de> for SeqAccess {
+#[doc(hidden)]
+struct VariantAccess<'de> {
+	de: Deserializer<'de>,
+	name: String,
+}
+
+impl<'de> de::VariantAccess<'de> for VariantAccess<'de> {
 	type Error = Error;
 
 	fn unit_variant(self) -> Result<()> {
-		de::Deserialize::deserialize(self.de)
+		// `"A"` or `{ "A": null }`
+		de::Deserializer::deserialize_unit(self.de, de::IgnoredAny).map(|_| ())
 	}
 
 	fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value>
 		where T: de::DeserializeSeed<'de>
 	{
 		// `{ "N": u8 }`
-		let v = self.de.input.get_item(self.key.unwrap());
-		seed.deserialize( Deserializer::from_value(v) )
+		let name = self.name;
+		seed.deserialize(self.de).map_err(|e| e.in_field(&name))
 	}
 
 	fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value>
 		where V: de::Visitor<'de>
 	{
 		// `{ "T": [u8, u8] }`
-		let v = self.de.input.get_item(self.key.unwrap());
-		de::Deserializer::deserialize_tuple(Deserializer::from_value(v), len, visitor)
+		let name = self.name;
+		de::Deserializer::deserialize_tuple(self.de, len, visitor).map_err(|e| e.in_field(&name))
 	}
 
 	fn struct_variant<V>(self, fields: &'static [&'static str], visitor: V) -> Result<V::Value>
 		where V: de::Visitor<'de>
 	{
 		// `{ "S": {r: u8, g: u8, b: u8} }`
-		let v = self.de.input.get_item(self.key.unwrap());
-		de::Deserializer::deserialize_struct(Deserializer::from_value(v), "", fields, visitor)
+		let name = self.name;
+		de::Deserializer::deserialize_struct(self.de, "", fields, visitor).map_err(|e| e.in_field(&name))
 	}
-
 }
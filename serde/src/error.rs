@@ -2,18 +2,111 @@ use std;
 use std::fmt::{self, Display};
 
 use serde::{ser, de};
+use sciter::Value;
 
 
 /// Result type for serialization.
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// Error type for serialization.
+///
+/// Errors of nested values are wrapped into [`Error::At`](#variant.At) with the path to the value,
+/// so they are displayed like `settings.proxies[3].port: expected integer, got string`.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Error {
 	Message(String),
 	Unimplemented,
 	UnsupportedType,
 	ExpectedType(String),
+	/// An integer that doesn't fit into the target type.
+	Overflow {
+		/// The integer value.
+		value: String,
+		/// The target type.
+		target: &'static str,
+	},
+	/// An error of the nested field or element.
+	At {
+		/// Path to the offending value, like `items[2].id`.
+		path: String,
+		/// The error itself.
+		error: Box<Error>,
+	},
+	/// A failed script call, see [`ScriptCall`](trait.ScriptCall.html).
+	Sciter(sciter::Error),
+}
+
+impl Error {
+	/// Path to the offending value; empty for the top-level value.
+	pub fn path(&self) -> &str {
+		match *self {
+			Error::At { ref path, .. } => path,
+			_ => "",
+		}
+	}
+
+	/// The error without its location.
+	pub fn inner(&self) -> &Error {
+		match *self {
+			Error::At { ref error, .. } => error,
+			_ => self,
+		}
+	}
+
+	// `expected integer, got string`
+	pub(crate) fn expected(what: &str, given: &Value) -> Self {
+		Error::ExpectedType(format!("expected {}, got {}", what, type_name(given)))
+	}
+
+	// Prepend the struct field or the map key to the error path.
+	pub(crate) fn in_field(self, name: &str) -> Self {
+		match self {
+			Error::At { path, error } => {
+				let path = if path.starts_with('[') {
+					format!("{}{}", name, path)
+				} else {
+					format!("{}.{}", name, path)
+				};
+				Error::At { path: path, error: error }
+			},
+			other => Error::At { path: name.to_string(), error: Box::new(other) },
+		}
+	}
+
+	// Prepend the sequence index to the error path.
+	pub(crate) fn in_index(self, index: usize) -> Self {
+		self.in_field(&format!("[{}]", index))
+	}
+}
+
+// Name of the value type for error messages.
+fn type_name(value: &Value) -> &'static str {
+	use sciter::value::VALUE_TYPE::*;
+	match value.get_type() {
+		T_UNDEFINED => "undefined",
+		T_NULL => "null",
+		T_BOOL => "bool",
+		T_INT => "integer",
+		T_FLOAT => "float",
+		T_STRING => "string",
+		T_DATE => "date",
+		T_CURRENCY => "currency",
+		T_LENGTH => "length",
+		T_ARRAY => "array",
+		T_MAP => "map",
+		T_FUNCTION => "function",
+		T_BYTES => "bytes",
+		T_OBJECT => "object",
+		T_DOM_OBJECT => "element",
+		T_RESOURCE => "resource",
+		T_RANGE => "range",
+		T_DURATION => "duration",
+		T_ANGLE => "angle",
+		T_COLOR => "color",
+		T_ENUM => "enum",
+		T_ASSET => "asset",
+		T_UNKNOWN => "unknown",
+	}
 }
 
 impl ser::Error for Error {
@@ -29,12 +122,24 @@ impl de::Error for Error {
 }
 
 impl std::error::Error for Error {
+	#[allow(deprecated)]
 	fn description(&self) -> &str {
 		match *self {
 			Error::Message(ref msg) => msg,
 			Error::ExpectedType(ref msg) => msg,
 			Error::Unimplemented => "unimplemented",
 			Error::UnsupportedType => "unsupported",
+			Error::Overflow { .. } => "integer overflow",
+			Error::At { ref error, .. } => error.description(),
+			Error::Sciter(_) => "script call failed",
+		}
+	}
+
+	// `Sciter` is displayed as the wrapped error, so its cause comes next.
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match *self {
+			Error::Sciter(ref e) => e.source(),
+			_ => None,
 		}
 	}
 }
@@ -42,10 +147,25 @@ impl std::error::Error for Error {
 impl Display for Error {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		match self {
-			Error::Message(ref msg) => write!(f, "error: {}", msg),
-			Error::ExpectedType(ref msg) => write!(f, "expected: {}", msg),
+			Error::Message(ref msg) => write!(f, "{}", msg),
+			Error::ExpectedType(ref msg) => write!(f, "{}", msg),
 			Error::UnsupportedType => write!(f, "unsupported type"),
 			Error::Unimplemented => write!(f, "unimplemented"),
+			Error::Overflow { ref value, target } => write!(f, "integer overflow: {} does not fit into {}", value, target),
+			Error::At { ref path, ref error } => write!(f, "{}: {}", path, error),
+			Error::Sciter(ref e) => write!(f, "{}", e),
 		}
 	}
 }
+
+impl From<sciter::Error> for Error {
+	fn from(e: sciter::Error) -> Self {
+		Error::Sciter(e)
+	}
+}
+
+impl From<sciter::value::ScriptError> for Error {
+	fn from(e: sciter::value::ScriptError) -> Self {
+		Error::Sciter(e.into())
+	}
+}
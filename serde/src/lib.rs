@@ -20,39 +20,74 @@ you can also use direct serialization between your data and `sciter::Value`.
 + Array (`&[T]`, `Vec<T>`)
 + Object (key-value mapping like `struct` or `HashMap`, `BTreeMap`, etc.)
 
-Unsupported:
+Sciter-specific types are supported via adapter modules for `#[serde(with = "...")]`
+or the corresponding wrapper types; they are stored as the typed Sciter values
+and can be deserialized from both the typed values and their plain forms:
+
++ Date ([`date`](date/index.html), [`Date`](struct.Date.html))
++ Currency ([`currency`](currency/index.html), [`Currency`](struct.Currency.html))
++ Length ([`length`](length/index.html), [`Length`](struct.Length.html))
++ Range ([`range`](range/index.html), [`Range`](struct.Range.html))
++ Duration ([`duration`](duration/index.html), [`Duration`](struct.Duration.html))
++ Angle ([`angle`](angle/index.html), [`Angle`](struct.Angle.html))
++ Color ([`color`](color/index.html), [`Color`](struct.Color.html))
 
-- Date
-- Currency
-- Length
-- Range
-- Duration
-- Angle
-- Color
+```rust
+# #![doc(test(no_crate_inject))]
+#[macro_use]
+extern crate serde_derive;
+extern crate sciter;
+extern crate sciter_serde;
+
+#[derive(Serialize, Deserialize)]
+struct Style {
+	#[serde(with = "sciter_serde::color")]
+	color: u32,
+	opacity: sciter_serde::Duration,
+}
+
+fn main() {
+	let style = Style { color: 0xFF00_00FF, opacity: sciter_serde::Duration(0.5) };
+	let v = sciter_serde::to_value(&style).unwrap();
+	assert!(v["color"].is_color());
+	assert!(v["opacity"].is_duration());
+}
+```
 
 ## Supported types of the Serde data model
 
 * [x] `bool`
 * [x] integer types except the following:
-* [-] `i64`/`u64` - 64-bit integers stored as `f64` in Sciter
+* [x] `i64`/`u64` - stored as `i32` or `f64` if it fits losslessly, otherwise see [`Int64Repr`](enum.Int64Repr.html)
 * [x] strings
 * [x] byte arrays
 * [x] option
 * [x] unit (stored as `null`)
 * [x] unit struct (stored as `null`)
-* [x] unit variant (aka `enum`, stored just as enum index of `i32` type)
+* [x] unit variant (aka `enum`, stored as the variant name, see below)
 * [x] newtype struct (aka `struct Io(u32)`, stored as underlaying value)
-* [-] newtype variant
+* [x] newtype variant
 * [x] seq, like vector (stored as array)
 * [x] tuple (stored as array)
 * [x] tuple struct (stored as array)
-* [-] tuple variant
+* [x] tuple variant
 * [x] map (stored as map)
 * [x] struct (stored as map)
-* [-] struct variant
+* [x] struct variant
 
 See the [Serde data model](https://serde.rs/data-model.html) for reference.
 
+Enums are externally tagged by default: `E::A` is stored as `"A"`, `E::N(1)` as `{"N": 1}`,
+`E::T(1, 2)` as `{"T": [1, 2]}` and `E::S { x: 1 }` as `{"S": {"x": 1}}`.
+They can be adjacently tagged instead (`{"t": "N", "c": 1}`) and variants can be identified
+by their index rather than by name, see [`Options`](struct.Options.html)
+with [`to_value_with`](fn.to_value_with.html) and [`from_value_with`](fn.from_value_with.html).
+Note that unit variants are stored by name (as they always were), not by index as documented before,
+see [`VariantTag`](enum.VariantTag.html).
+
+Script functions can be called with serializable arguments and a deserializable result
+via [`ScriptCall`](trait.ScriptCall.html), see also [`to_args`](fn.to_args.html).
+
 # Examples
 
 ```rust
@@ -142,14 +177,24 @@ extern crate serde;
 extern crate sciter;
 
 
+mod adapters;
+mod call;
 mod error;
+mod options;
 mod ser;
 mod de;
 
 #[doc(inline)]
-pub use ser::to_value;
+pub use ser::{to_value, to_value_with};
+
+#[doc(inline)]
+pub use de::{from_value, from_value_with, Deserializer};
 
 #[doc(inline)]
-pub use de::from_value;
+pub use call::{to_args, ScriptCall};
 
 pub use error::{Result, Error};
+pub use options::{EnumRepr, Int64Repr, Options, VariantTag};
+
+pub use adapters::{color, duration, angle, date, currency, length, range};
+pub use adapters::{Color, Duration, Angle, Date, Currency, Length, Range};
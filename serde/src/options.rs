@@ -0,0 +1,87 @@
+//! Serialization options.
+
+/// How enum variants are stored in Sciter values,
+/// see https://serde.rs/enum-representations.html.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnumRepr {
+	/// Externally tagged: `"A"`, `{"N": 1}`, `{"T": [1, 2]}`, `{"S": {"x": 1}}`.
+	///
+	/// Unit variants are stored as the bare variant identifier.
+	External,
+
+	/// Adjacently tagged: `{"t": "A"}`, `{"t": "N", "c": 1}`, `{"t": "T", "c": [1, 2]}`.
+	///
+	/// The content key is omitted for unit variants.
+	Adjacent {
+		/// Key of the variant identifier.
+		tag: &'static str,
+		/// Key of the variant content.
+		content: &'static str,
+	},
+}
+
+/// How a variant is identified in the serialized enum.
+///
+/// # Compatibility
+///
+/// Earlier versions documented unit variants as stored by their `i32` index, while they were
+/// actually stored by name. The default `Name` keeps the actual format; use `Index`
+/// to get the documented one. Deserialization accepts both names and indices regardless of this option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariantTag {
+	/// The variant name as a string, like `"A"`.
+	Name,
+	/// The variant index as an integer, like `0`.
+	Index,
+}
+
+/// How 64-bit integers (`i64`, `u64` and so on) are stored.
+///
+/// Sciter has only 32-bit integers and `f64` floats, so an integer is stored as an integer
+/// if it fits into `i32`, or as a float if it is exactly representable as `f64` (i.e. within `±2^53`).
+/// The rest of values are handled according to this option.
+///
+/// Deserialization accepts all of these forms regardless of the option,
+/// and for every integer type: a decimal string like `"42"` is a valid `u8` as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Int64Repr {
+	/// Fail with [`Error::Overflow`](enum.Error.html#variant.Overflow).
+	Strict,
+	/// Store as a decimal string, which can be passed to `BigInt()` in script.
+	String,
+}
+
+/// Options for [`to_value_with`](fn.to_value_with.html) and [`from_value_with`](fn.from_value_with.html).
+///
+/// The default options store enums externally tagged by variant names
+/// and reject 64-bit integers that can't be stored losslessly.
+///
+/// ```rust
+/// use sciter_serde::{EnumRepr, Int64Repr, Options, VariantTag};
+///
+/// let options = Options {
+/// 	enum_repr: EnumRepr::Adjacent { tag: "type", content: "value" },
+/// 	variant_tag: VariantTag::Index,
+/// 	int64: Int64Repr::String,
+/// };
+/// assert_ne!(options, Options::default());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+	/// Enum representation.
+	pub enum_repr: EnumRepr,
+	/// Variant identifier.
+	pub variant_tag: VariantTag,
+	/// Representation of 64-bit integers.
+	pub int64: Int64Repr,
+}
+
+impl Default for Options {
+	fn default() -> Self {
+		Options {
+			enum_repr: EnumRepr::External,
+			variant_tag: VariantTag::Name,
+			int64: Int64Repr::Strict,
+		}
+	}
+}
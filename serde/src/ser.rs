@@ -1,13 +1,20 @@
 /// Serialization.
 use serde::ser::{self, Serialize};
 
+use adapters;
 use error::{Error, Result};
+use options::{EnumRepr, Int64Repr, Options, VariantTag};
 use sciter::{Value};
 
 
 /// Serialize the given data structure into Sciter value.
 pub fn to_value<T: ?Sized + Serialize>(value: &T) -> Result<Value> {
-	let mut p = Serializer { output: Value::new() };
+	to_value_with(value, &Options::default())
+}
+
+/// Serialize the given data structure into Sciter value using the specified options.
+pub fn to_value_with<T: ?Sized + Serialize>(value: &T, options: &Options) -> Result<Value> {
+	let mut p = Serializer { output: Value::new(), options: *options };
 	value.serialize(&mut p)?;
 	Ok(p.output)
 }
@@ -15,6 +22,53 @@ pub fn to_value<T: ?Sized + Serialize>(value: &T) -> Result<Value> {
 /// Implementation of serialization.
 pub struct Serializer {
 	output: Value,
+	options: Options,
+}
+
+impl Serializer {
+	// Serialize a nested value with the same options.
+	fn nested<T: ?Sized + Serialize>(&self, value: &T) -> Result<Value> {
+		to_value_with(value, &self.options)
+	}
+
+	// Store an integer that doesn't fit into `i32` or `f64` losslessly.
+	fn serialize_large<T: ToString>(&mut self, v: T) -> Result<()> {
+		match self.options.int64 {
+			Int64Repr::String => {
+				self.output = Value::from(v.to_string());
+				Ok(())
+			},
+			Int64Repr::Strict => Err(Error::Overflow { value: v.to_string(), target: "Sciter number" }),
+		}
+	}
+
+	// Identifier of the variant: its name or index.
+	fn variant(&self, index: u32, name: &'static str) -> Value {
+		match self.options.variant_tag {
+			VariantTag::Name => Value::from(name),
+			VariantTag::Index => Value::from(index as i32),
+		}
+	}
+
+	// Wrap the variant content according to the enum representation.
+	fn tagged(&self, variant: Value, content: Option<Value>) -> Value {
+		match (self.options.enum_repr, content) {
+			(EnumRepr::External, None) => variant,
+			(EnumRepr::External, Some(content)) => {
+				let mut result = Value::map();
+				result.set_item(variant, content);
+				result
+			},
+			(EnumRepr::Adjacent { tag, content: key }, content) => {
+				let mut result = Value::map();
+				result.set_item(tag, variant);
+				if let Some(content) = content {
+					result.set_item(key, content);
+				}
+				result
+			},
+		}
+	}
 }
 
 // Helper structure for serialization of sequence data types (array, map, tuple ans so on).
@@ -46,6 +100,11 @@ impl<'a> SeqSerializer<'a> {
 	}
 }
 
+// Name of the map key for error messages.
+fn key_name(key: &Value) -> String {
+	key.as_string().unwrap_or_else(|| key.to_string())
+}
+
 // serde traits implementation
 
 impl<'a> ser::SerializeSeq for SeqSerializer<'a> {
@@ -58,7 +117,8 @@ impl<'a> ser::SerializeSeq for SeqSerializer<'a> {
 	}
 
 	fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
-		let dst = to_value(value)?;
+		let index = self.output.len();
+		let dst = self.ser.nested(value).map_err(|e| e.in_index(index))?;
 		self.output.push(dst);
 		Ok(())
 	}
@@ -73,18 +133,21 @@ impl<'a> ser::SerializeMap for SeqSerializer<'a> {
 	}
 
 	fn serialize_entry<K, V>(&mut self, key: &K, value: &V) -> Result<()> where K: ?Sized + Serialize, V: ?Sized + Serialize {
-		self.output.set_item(to_value(key)?, to_value(value)?);
+		let key = self.ser.nested(key)?;
+		let value = self.ser.nested(value).map_err(|e| e.in_field(&key_name(&key)))?;
+		self.output.set_item(key, value);
 		Ok(())
 	}
 
 	fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
-		self.key = Some(to_value(key)?);
+		self.key = Some(self.ser.nested(key)?);
 		Ok(())
 	}
 
 	fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
-		let key = self.key.take();
-		self.output.set_item(key.unwrap(), to_value(value)?);
+		let key = self.key.take().unwrap();
+		let value = self.ser.nested(value).map_err(|e| e.in_field(&key_name(&key)))?;
+		self.output.set_item(key, value);
 		Ok(())
 	}
 }
@@ -98,7 +161,7 @@ impl<'a> ser::SerializeStruct for SeqSerializer<'a> {
 	}
 
 	fn serialize_field<T: ?Sized + Serialize>(&mut self, key: &'static str, value: &T) -> Result<()> {
-		self.output.set_item(key, to_value(value)?);
+		self.output.set_item(key, self.ser.nested(value).map_err(|e| e.in_field(key))?);
 		Ok(())
 	}
 }
@@ -109,15 +172,13 @@ impl<'a> ser::SerializeStructVariant for SeqSerializer<'a> {
 
 	fn end(self) -> Result<()> {
 		// self.output: map
-		// self.outer: left key
-		let mut result = Value::new();
-		result.set_item(self.outer.unwrap(), self.output);
-		self.ser.output = result;
+		// self.outer: variant identifier
+		self.ser.output = self.ser.tagged(self.outer.unwrap(), Some(self.output));
 		Ok(())
 	}
 
 	fn serialize_field<T: ?Sized + Serialize>(&mut self, key: &'static str, value: &T) -> Result<()> {
-		self.output.set_item(key, to_value(value)?);
+		self.output.set_item(key, self.ser.nested(value).map_err(|e| e.in_field(key))?);
 		Ok(())
 	}
 }
@@ -154,10 +215,8 @@ impl<'a> ser::SerializeTupleVariant for SeqSerializer<'a> {
 
 	fn end(self) -> Result<()> {
 		// self.output: array
-		// self.outer: left key
-		let mut result = Value::new();
-		result.set_item(self.outer.unwrap(), self.output);
-		self.ser.output = result;
+		// self.outer: variant identifier
+		self.ser.output = self.ser.tagged(self.outer.unwrap(), Some(self.output));
 		Ok(())
 	}
 
@@ -207,19 +266,31 @@ impl<'a> ser::Serializer for &'a mut Serializer {
   }
 
   fn serialize_u32(self, v: u32) -> Result<()> {
-  	if v <= i32::max_value() as u32 {
+  	if v <= i32::MAX as u32 {
   		self.serialize_i32(v as i32)
   	} else {
   		self.serialize_f64(v as f64)
   	}
   }
 
-  fn serialize_i64(self, _v: i64) -> Result<()> {
-  	Err(Error::UnsupportedType)
+  // 64-bit integers are stored as `i32` or `f64` if they fit, see `Int64Repr`.
+  fn serialize_i64(self, v: i64) -> Result<()> {
+  	const EXACT: i64 = 1 << 53;
+  	if (i32::MIN as i64..=i32::MAX as i64).contains(&v) {
+  		self.serialize_i32(v as i32)
+  	} else if (-EXACT..=EXACT).contains(&v) {
+  		self.serialize_f64(v as f64)
+  	} else {
+  		self.serialize_large(v)
+  	}
   }
 
-  fn serialize_u64(self, _v: u64) -> Result<()> {
-  	Err(Error::UnsupportedType)
+  fn serialize_u64(self, v: u64) -> Result<()> {
+  	if v <= i64::MAX as u64 {
+  		self.serialize_i64(v as i64)
+  	} else {
+  		self.serialize_large(v)
+  	}
   }
 
   // Float values.
@@ -270,50 +341,61 @@ impl<'a> ser::Serializer for &'a mut Serializer {
   	self.serialize_none()
   }
 
-  // Enums. Serialized as an externally tagged enum representation,
-  // see https://serde.rs/enum-representations.html.
+  // Enums. Serialized as an externally or adjacently tagged enum representation
+  // (see `EnumRepr`), the examples below are externally tagged.
 
   // A unit variant of enum, like `E::A` of `enum E { A, B }`.
-  fn serialize_unit_variant(self, _name: &'static str, _index: u32, value: &'static str)
+  fn serialize_unit_variant(self, _name: &'static str, index: u32, variant: &'static str)
   	-> Result<()>
   {
   	// `"A"`
-  	self.serialize_str(value)
+  	let left = self.variant(index, variant);
+  	self.output = self.tagged(left, None);
+  	Ok(())
   }
 
   // For example the `E::N` in `enum E { N(u8) }`.
-  fn serialize_newtype_variant<T>(self, _name: &'static str, _index: u32, variant: &'static str, value: &T)
+  fn serialize_newtype_variant<T>(self, _name: &'static str, index: u32, variant: &'static str, value: &T)
   	-> Result<()> where T: ?Sized + Serialize
   {
   	// `{ "N": u8 }`
-  	self.output.set_item(to_value(variant)?, to_value(value)?);
+  	let left = self.variant(index, variant);
+  	let right = self.nested(value)?;
+  	self.output = self.tagged(left, Some(right));
   	Ok(())
   }
 
   // For example the `E::T` in `enum E { T(u8, u8) }`.
-  fn serialize_tuple_variant(self, _name: &'static str, _index: u32, value: &'static str, _len: usize)
+  fn serialize_tuple_variant(self, _name: &'static str, index: u32, variant: &'static str, _len: usize)
   	 -> Result<Self::SerializeTupleVariant>
   {
   	// `{ "T": [u8, u8] }`
-  	let left = to_value(value)?;
+  	let left = self.variant(index, variant);
   	Ok(SeqSerializer::with_outer(self, left, Value::array(0)))
   }
 
   // For example the `E::S` in `enum E { S { r: u8, g: u8, b: u8 } }`.
-  fn serialize_struct_variant(self, _name: &'static str, _index: u32, value: &'static str, _len: usize)
+  fn serialize_struct_variant(self, _name: &'static str, index: u32, variant: &'static str, _len: usize)
   	 -> Result<Self::SerializeStructVariant>
   {
   	// `{ "S": {r: u8, g: u8, b: u8} }`
-  	let left = to_value(value)?;
+  	let left = self.variant(index, variant);
   	Ok(SeqSerializer::with_outer(self, left, Value::map()))
   }
 
   // New-type struct, like `struct Celcius(u32)`.
-  fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<()>
+  fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<()>
   	where T: ?Sized + Serialize
   {
-  	// Serialize the inner itself.
-  	value.serialize(self)
+  	if adapters::is_adapter(name) {
+  		// Sciter-specific value, like `sciter_serde::Color`.
+  		let plain = self.nested(value)?;
+  		self.output = adapters::to_typed(name, plain)?;
+  		Ok(())
+  	} else {
+  		// Serialize the inner itself.
+  		value.serialize(self)
+  	}
   }
 
   // A variably sized heterogeneous sequence of values, for example `Vec<T>` or `HashSet<T>`.
@@ -8,7 +8,8 @@ extern crate serde_derive;
 extern crate serde_bytes;
 extern crate serde;
 
-use sciter_serde::{from_value, to_value};
+use sciter::Value;
+use sciter_serde::{from_value, to_value, from_value_with, to_value_with, EnumRepr, Error, Int64Repr, Options, VariantTag};
 
 
 // serialize, deserialize and compare with the original value.
@@ -28,6 +29,17 @@ macro_rules! the_same {
 	}
 }
 
+// the same as above, but with the specified options;
+// returns the intermediate value.
+fn the_same_with<V>(actual: V, options: &Options) -> Value
+	where V: serde::Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug + 'static
+{
+	let sv = to_value_with(&actual, options).expect("to_value_with");
+	let decoded: V = from_value_with(&sv, options).expect("from_value_with");
+	assert_eq!(actual, decoded);
+	sv
+}
+
 #[test]
 fn basic_types() {
 	the_same!(true);
@@ -36,18 +48,18 @@ fn basic_types() {
 	the_same!(7i8);
 	the_same!(7i16);
 	the_same!(7i32);
-	// the_same!(7i64); there are no 64-bit integers in Sciter, only floats.
+	the_same!(7i64);
 
 	the_same!(7u8);
 	the_same!(7u16);
 	the_same!(7u32);
-	// the_same!(7u64); ditto
+	the_same!(7u64);
 
 	the_same!(7f32);
 	the_same!(7f64);
 
 	the_same!(-7i32);
-	// the_same!(-7isize);
+	the_same!(-7isize);
 
 
 	the_same!(Box::new(7));
@@ -127,10 +139,13 @@ fn enums() {
 	}
 
 	the_same!(Test::Zero);
-	// the_same!(Test::One(7));
-	// the_same!(Test::Two(7, 7));
-	// the_same!(Test::Three { x: 1, y: 2, z: 3});
+	the_same!(Test::One(7));
+	the_same!(Test::Two(7, 7));
+	the_same!(Test::Three { x: 1, y: 2, z: 3});
 	the_same!(Test::Five);
+
+	the_same!(vec![Test::Zero, Test::One(1), Test::Five]);
+	the_same!(Some(Test::Two(1, 2)));
 }
 
 #[test]
@@ -145,19 +160,95 @@ fn arrays() {
 #[test]
 #[should_panic]
 fn unsupported_u64() {
-	the_same!(7u64);
+	the_same!(u64::MAX);
 }
 
 #[test]
 #[should_panic]
 fn unsupported_i64() {
-	the_same!(-7i64);
+	the_same!(-(1i64 << 53) - 1);
 }
 
 #[test]
 #[should_panic]
 fn unsupported_usize() {
-	the_same!(7usize);
+	the_same!(usize::MAX);
+}
+
+#[test]
+fn lossless_64bit_integers() {
+	let exact = 1i64 << 53;
+	for &n in &[0, 7, -7, i32::MAX as i64 + 1, i32::MIN as i64 - 1, exact, -exact] {
+		the_same!(n);
+		assert!(to_value(&n).unwrap().is_int() || to_value(&n).unwrap().is_float());
+	}
+	the_same!(exact as u64);
+
+	let options = Options { int64: Int64Repr::String, .. Options::default() };
+	for &n in &[exact + 1, -exact - 1, i64::MAX, i64::MIN] {
+		let v = the_same_with(n, &options);
+		assert_eq!(v, Value::from(n.to_string()));
+	}
+	let v = the_same_with(u64::MAX, &options);
+	assert_eq!(v, Value::from("18446744073709551615"));
+
+	// the deserializer accepts any of these forms
+	let n: u64 = from_value(&Value::from("18446744073709551615")).unwrap();
+	assert_eq!(n, u64::MAX);
+	let n: i64 = from_value(&Value::from(-7.0)).unwrap();
+	assert_eq!(n, -7);
+	let n: u32 = from_value(&Value::from(u32::MAX as f64)).unwrap();
+	assert_eq!(n, u32::MAX);
+	assert!(from_value::<i64>(&Value::from(0.5)).is_err());
+}
+
+#[test]
+fn integer_overflow_names_field() {
+	#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+	struct Record {
+		id: u64,
+	}
+
+	#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+	struct Table {
+		records: Vec<Record>,
+	}
+
+	let table = Table { records: vec![Record { id: 1 }, Record { id: u64::MAX }] };
+	let err = to_value(&table).unwrap_err();
+	assert_eq!(err.path(), "records[1].id");
+	assert_eq!(err.inner(), &Error::Overflow {
+		value: "18446744073709551615".to_string(),
+		target: "Sciter number",
+	});
+
+	let options = Options { int64: Int64Repr::String, .. Options::default() };
+	let v = to_value_with(&table, &options).unwrap();
+	assert_eq!(from_value::<Table>(&v).unwrap(), table);
+
+	#[derive(Deserialize, Debug)]
+	struct Narrow {
+		#[allow(dead_code)]
+		records: Vec<NarrowRecord>,
+	}
+
+	#[derive(Deserialize, Debug)]
+	struct NarrowRecord {
+		#[allow(dead_code)]
+		id: u8,
+	}
+
+	let err = from_value::<Narrow>(&v).unwrap_err();
+	assert_eq!(err.to_string(), "records[1].id: integer overflow: 18446744073709551615 does not fit into u8");
+}
+
+#[test]
+fn script_errors() {
+	use std::error::Error as StdError;
+
+	let err = Error::from(sciter::value::ScriptError::new("failed"));
+	assert_eq!(err.to_string(), "script error");
+	assert_eq!(err.source().unwrap().to_string(), "failed");
 }
 
 #[test]
@@ -199,3 +290,184 @@ fn struct_variant() {
 
 	the_same!(Test::Three { x: 1, y: 2, z: 3 });
 }
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+enum Shape {
+	Empty,
+	Circle(f64),
+	Line(i32, i32),
+	Rect { width: i32, height: i32 },
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+struct Scene {
+	shapes: Vec<Shape>,
+	focus: Option<Shape>,
+}
+
+fn scene() -> Scene {
+	Scene {
+		shapes: vec![Shape::Empty, Shape::Circle(0.5), Shape::Line(1, 2), Shape::Rect { width: 3, height: 4 }],
+		focus: Some(Shape::Line(5, 6)),
+	}
+}
+
+#[test]
+fn externally_tagged_variants() {
+	let options = Options::default();
+
+	let v = the_same_with(Shape::Empty, &options);
+	assert_eq!(v, Value::from("Empty"));
+
+	let v = the_same_with(Shape::Circle(0.5), &options);
+	assert_eq!(v, Value::parse(r#"{ "Circle": 0.5 }"#).unwrap());
+
+	let v = the_same_with(Shape::Line(1, 2), &options);
+	assert_eq!(v, Value::parse(r#"{ "Line": [1, 2] }"#).unwrap());
+
+	let v = the_same_with(Shape::Rect { width: 3, height: 4 }, &options);
+	assert_eq!(v, Value::parse(r#"{ "Rect": { "width": 3, "height": 4 } }"#).unwrap());
+
+	the_same_with(scene(), &options);
+}
+
+#[test]
+fn adjacently_tagged_variants() {
+	let options = Options {
+		enum_repr: EnumRepr::Adjacent { tag: "type", content: "value" },
+		.. Options::default()
+	};
+
+	let v = the_same_with(Shape::Empty, &options);
+	assert_eq!(v, Value::parse(r#"{ "type": "Empty" }"#).unwrap());
+
+	let v = the_same_with(Shape::Circle(0.5), &options);
+	assert_eq!(v, Value::parse(r#"{ "type": "Circle", "value": 0.5 }"#).unwrap());
+
+	let v = the_same_with(Shape::Line(1, 2), &options);
+	assert_eq!(v, Value::parse(r#"{ "type": "Line", "value": [1, 2] }"#).unwrap());
+
+	let v = the_same_with(Shape::Rect { width: 3, height: 4 }, &options);
+	assert_eq!(v, Value::parse(r#"{ "type": "Rect", "value": { "width": 3, "height": 4 } }"#).unwrap());
+
+	let v = the_same_with(scene(), &options);
+	assert_eq!(v["focus"]["type"], Value::from("Line"));
+
+	// externally tagged values are not accepted here
+	assert!(from_value_with::<Shape>(&Value::from("Empty"), &options).is_err());
+	assert!(from_value_with::<Shape>(&Value::parse(r#"{ "Circle": 0.5 }"#).unwrap(), &options).is_err());
+}
+
+#[test]
+fn variant_indices() {
+	let options = Options {
+		variant_tag: VariantTag::Index,
+		.. Options::default()
+	};
+
+	let v = the_same_with(Shape::Empty, &options);
+	assert_eq!(v, Value::from(0));
+
+	let v = the_same_with(Shape::Rect { width: 3, height: 4 }, &options);
+	assert_eq!(v.key_at(0), Value::from(3));
+
+	the_same_with(scene(), &options);
+
+	let options = Options {
+		enum_repr: EnumRepr::Adjacent { tag: "t", content: "c" },
+		variant_tag: VariantTag::Index,
+		.. Options::default()
+	};
+
+	let v = the_same_with(Shape::Circle(0.5), &options);
+	assert_eq!(v, Value::parse(r#"{ "t": 1, "c": 0.5 }"#).unwrap());
+
+	the_same_with(scene(), &options);
+
+	// indices and names are both accepted on deserialization
+	assert_eq!(from_value::<Shape>(&Value::from(0)).unwrap(), Shape::Empty);
+	assert_eq!(from_value_with::<Shape>(&Value::from("Empty"), &Options { variant_tag: VariantTag::Index, .. Options::default() }).unwrap(), Shape::Empty);
+}
+
+#[test]
+fn sciter_types() {
+	use std::time::{Duration, UNIX_EPOCH};
+	use sciter::value::{Currency, Length, Range};
+
+	#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+	struct Typed {
+		#[serde(with = "sciter_serde::color")]
+		color: u32,
+		#[serde(with = "sciter_serde::duration")]
+		delay: f64,
+		#[serde(with = "sciter_serde::angle")]
+		rotation: f64,
+		#[serde(with = "sciter_serde::date")]
+		created: std::time::SystemTime,
+		#[serde(with = "sciter_serde::currency")]
+		price: Currency,
+		#[serde(with = "sciter_serde::length")]
+		width: Length,
+		#[serde(with = "sciter_serde::range")]
+		selection: Range,
+	}
+
+	let typed = Typed {
+		color: 0xFF00_00FF,
+		delay: 0.5,
+		rotation: 1.5,
+		created: UNIX_EPOCH + Duration::from_millis(1_500_000_000_123),
+		price: Currency::from_units(12),
+		width: Length::Px(12.0),
+		selection: Range::new(1, 10),
+	};
+	the_same!(typed.clone());
+
+	let v = to_value(&typed).unwrap();
+	assert!(v["color"].is_color());
+	assert!(v["delay"].is_duration());
+	assert!(v["rotation"].is_angle());
+	assert!(v["created"].is_date());
+	assert!(v["price"].is_currency());
+	assert!(v["width"].is_length());
+	assert!(v["selection"].is_range());
+	assert_eq!(v["width"].to_length(), Some(Length::Px(12.0)));
+
+	// plain forms are accepted as well
+	let plain = Value::parse(r#"{
+		"color": 4278190335,
+		"delay": 0.5,
+		"rotation": 1.5,
+		"created": 1500000000123,
+		"price": "12.0000",
+		"width": "12px",
+		"selection": [1, 10]
+	}"#).unwrap();
+	assert_eq!(from_value::<Typed>(&plain).unwrap(), typed);
+
+	// wrappers
+	use sciter_serde::{Angle, Color, Date};
+	let v = to_value(&(Color(0xFF), Angle(1.0), Date(UNIX_EPOCH))).unwrap();
+	assert!(v[0].is_color());
+	assert!(v[1].is_angle());
+	assert!(v[2].is_date());
+	the_same!((Color(0xFF), sciter_serde::Length(Length::Percent(50.0)), sciter_serde::Range(Range::new(0, 1))));
+	the_same!(vec![sciter_serde::Currency(Currency::from_raw(-5)), sciter_serde::Currency(Currency::from_units(1))]);
+	the_same!(Some(sciter_serde::Duration(0.25)));
+
+	// invalid plain forms
+	struct Raw(&'static str, f64);
+	impl serde::Serialize for Raw {
+		fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+			serializer.serialize_newtype_struct(self.0, &self.1)
+		}
+	}
+	for &v in &[-1.0, 0.5, 4294967296.0, f64::NAN] {
+		assert!(to_value(&Raw("$sciter::color", v)).is_err());
+	}
+	assert!(to_value(&Raw("$sciter::color", 4294967295.0)).unwrap().is_color());
+	for &v in &[f64::NAN, f64::INFINITY, 1e300, -1e300] {
+		assert!(to_value(&Raw("$sciter::date", v)).is_err());
+		assert!(from_value::<Date>(&Value::from(v)).is_err());
+	}
+}
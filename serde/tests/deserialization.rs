@@ -8,8 +8,10 @@ extern crate serde_derive;
 extern crate serde_bytes;
 extern crate serde;
 
+use std::borrow::Cow;
+
 use sciter::{Value};
-use sciter_serde::{from_value, to_value};
+use sciter_serde::{from_value, to_value, Deserializer};
 
 
 #[test]
@@ -92,3 +94,92 @@ fn structs() {
 
 	assert_eq!(a, e);
 }
+
+#[test]
+fn borrowed() {
+	#[derive(Deserialize)]
+	struct Test<'a> {
+		#[serde(borrow)]
+		bytes: Option<&'a [u8]>,
+		name: String,
+	}
+
+	let v = Value::from(b"hello".as_ref());
+	let bytes: &[u8] = from_value(&v).unwrap();
+	assert_eq!(bytes, b"hello".as_ref());
+
+	let v = Value::parse(r#"{ "name": "test" }"#).unwrap();
+	let t: Test = serde::Deserialize::deserialize(Deserializer::from_value(&v)).unwrap();
+	assert!(t.bytes.is_none());
+	assert_eq!(t.name, "test");
+
+	// nested byte arrays can't be borrowed
+	let mut v = v;
+	v.set_item("bytes", b"hello".as_ref());
+	assert!(from_value::<Test>(&v).is_err());
+
+	#[derive(Deserialize)]
+	struct Nested<'a> {
+		#[serde(borrow)]
+		bytes: Cow<'a, [u8]>,
+	}
+	let t: Nested = from_value(&v).unwrap();
+	assert_eq!(t.bytes.as_ref(), b"hello".as_ref());
+}
+
+#[test]
+fn error_paths() {
+	#[derive(Deserialize, Debug)]
+	#[allow(dead_code)]
+	struct Proxy {
+		host: String,
+		port: u16,
+	}
+
+	#[derive(Deserialize, Debug)]
+	#[allow(dead_code)]
+	struct Settings {
+		proxies: Vec<Proxy>,
+		mode: Option<Mode>,
+	}
+
+	#[derive(Deserialize, Debug)]
+	#[allow(dead_code)]
+	enum Mode {
+		Manual { timeout: u32 },
+	}
+
+	#[derive(Deserialize, Debug)]
+	#[allow(dead_code)]
+	struct Root {
+		settings: Settings,
+	}
+
+	// numeric strings are accepted for any integer width, so `proxies[3]` is fine
+	let v = Value::parse(r#"{ "settings": { "proxies": [
+		{ "host": "a", "port": 1 },
+		{ "host": "b", "port": 2 },
+		{ "host": "c", "port": 3 },
+		{ "host": "d", "port": "80" },
+		{ "host": "e", "port": "http" }
+	] } }"#).unwrap();
+
+	let e = from_value::<Root>(&v).unwrap_err();
+	assert_eq!(e.path(), "settings.proxies[4].port");
+	assert_eq!(e.to_string(), "settings.proxies[4].port: expected integer, got string");
+
+	let v = Value::parse(r#"{ "settings": { "proxies": [], "mode": { "Manual": { "timeout": true } } } }"#).unwrap();
+	let e = from_value::<Root>(&v).unwrap_err();
+	assert_eq!(e.to_string(), "settings.mode.Manual.timeout: expected integer, got bool");
+
+	let v = Value::parse(r#"{ "settings": { "proxies": [ { "host": "a" } ] } }"#).unwrap();
+	let e = from_value::<Root>(&v).unwrap_err();
+	assert_eq!(e.to_string(), "settings.proxies[0]: missing field `port`");
+
+	let e = from_value::<Root>(&Value::from(1)).unwrap_err();
+	assert_eq!(e.path(), "");
+
+	assert_eq!(from_value::<u8>(&Value::from("42")).unwrap(), 42);
+	assert_eq!(from_value::<i16>(&Value::from(" -7 ")).unwrap(), -7);
+	assert!(from_value::<u8>(&Value::from("256")).is_err());
+}
@@ -9,7 +9,7 @@ extern crate serde_bytes;
 extern crate serde;
 
 use sciter::{Value};
-use sciter_serde::{to_value};
+use sciter_serde::{to_args, to_value};
 
 
 #[test]
@@ -46,9 +46,12 @@ fn basic_types() {
 	assert!(v.is_float());
 
 	// 64-bit
-	// let v = to_value(&7u64).unwrap();
-	// assert!(v.is_float());
-	// assert_eq!(v, Value::from(7.0));
+	let v = to_value(&7u64).unwrap();
+	assert_eq!(v, Value::from(7));
+
+	let v = to_value(&(1u64 << 40)).unwrap();
+	assert!(v.is_float());
+	assert_eq!(v, Value::from((1u64 << 40) as f64));
 
 	// Option
 	// let v = to_value(&Some(7)).unwrap();
@@ -111,3 +114,30 @@ fn structs() {
 	assert_eq!(v.get_item("int"), Value::from(7) );
 	assert_eq!(v.get_item("seq").len(), 2);
 }
+
+#[test]
+fn script_args() {
+	let args = to_args(&()).unwrap();
+	assert!(args.is_empty());
+
+	let args = to_args(&(1, "x")).unwrap();
+	assert_eq!(args, vec![Value::from(1), Value::from("x")]);
+
+	let args = to_args(&[1, 2, 3]).unwrap();
+	assert_eq!(args.len(), 3);
+
+	// a single array argument
+	let items = vec![1, 2, 3];
+	let args = to_args(&(&items,)).unwrap();
+	assert_eq!(args.len(), 1);
+	assert!(args[0].is_array());
+
+	#[derive(Serialize)]
+	struct Query {
+		text: &'static str,
+	}
+
+	let args = to_args(&Query { text: "rust" }).unwrap();
+	assert_eq!(args.len(), 1);
+	assert_eq!(args[0].get_item("text"), Value::from("rust"));
+}
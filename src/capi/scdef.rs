@@ -302,6 +302,23 @@ pub struct SCN_INVALIDATE_RECT
 	pub invalid_rect: RECT,
 }
 
+#[repr(C)]
+/// This notification is sent on the UI thread as a result of `SciterPostCallback`.
+pub struct SCN_POSTED_NOTIFICATION
+{
+	/// `SC_POSTED_NOTIFICATION` here.
+	pub code: UINT,
+	/// `HWINDOW` of the window this callback was attached to.
+	pub hwnd: HWINDOW,
+
+	/// `wparam` passed to `SciterPostCallback`.
+	pub wparam: UINT_PTR,
+	/// `lparam` passed to `SciterPostCallback`.
+	pub lparam: UINT_PTR,
+	/// [out] The result returned by `SciterPostCallback` if it waits for the notification.
+	pub lreturn: UINT_PTR,
+}
+
 #[repr(C)]
 pub struct SCITER_CALLBACK_NOTIFICATION
 {
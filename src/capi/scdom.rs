@@ -8,7 +8,7 @@ MAKE_HANDLE!(#[doc = "Element native handle."] HELEMENT, _HELEMENT);
 MAKE_HANDLE!(#[doc = "Node native handle."] HNODE, _HNODE);
 
 #[repr(C)]
-#[derive(Debug, PartialOrd, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq)]
 /// Type of the result value for Sciter DOM functions.
 pub enum SCDOM_RESULT {
 	/// Function completed successfully.
@@ -48,6 +48,21 @@ pub enum SET_ELEMENT_HTML
 	SOH_INSERT_AFTER        = 5,
 }
 
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq)]
+/// `dom::Node.insert()` target.
+pub enum NODE_INS_TARGET
+{
+	/// Insert the node before this one.
+	NIT_BEFORE = 0,
+	/// Insert the node after this one.
+	NIT_AFTER = 1,
+	/// Append the node as the last child of this one.
+	NIT_APPEND = 2,
+	/// Insert the node as the first child of this one.
+	NIT_PREPEND = 3,
+}
+
 /// Bounding rectangle of the element.
 #[repr(C)]
 #[derive(Debug, PartialOrd, PartialEq)]
@@ -100,6 +115,17 @@ impl ELEMENT_AREAS {
 	}
 }
 
+/// `dom::Element.scroll_to_view()` flags.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq)]
+pub enum SCITER_SCROLL_FLAGS
+{
+	/// Scroll the element to the top of the view, otherwise just make it visible.
+	SCROLL_TO_TOP = 0x01,
+	/// Animate the scrolling.
+	SCROLL_SMOOTH = 0x10,
+}
+
 /// Collection of states (runtime flags) of a DOM element.
 ///
 /// They reflect CSS pseudo-classes that are used in selectors,
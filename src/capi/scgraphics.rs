@@ -21,7 +21,7 @@ pub type SC_COLOR = u32;
 
 
 #[repr(C)]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 /// Type of the result value for Sciter Graphics functions.
 pub enum GRAPHIN_RESULT {
 	/// E.g. not enough memory.
@@ -14,7 +14,7 @@ pub use capi::scdef::RESOURCE_TYPE;
 MAKE_HANDLE!(#[doc = "Request native handle."] HREQUEST, _HREQUEST);
 
 #[repr(C)]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 /// Type of the result value for Sciter Request functions.
 pub enum REQUEST_RESULT {
 	/// E.g. not enough memory.
@@ -34,7 +34,7 @@ impl VALUE {
 }
 
 #[repr(C)]
-#[derive(Debug, PartialOrd, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq)]
 pub enum VALUE_RESULT
 {
   OK_TRUE = -1,
@@ -78,7 +78,7 @@ pub enum VALUE_TYPE {
 	T_FLOAT,
 	/// Data is a Sciter internal string, unit is [`VALUE_UNIT_TYPE_STRING`].
 	T_STRING,
-	/// Data is `FILETIME` (64-bit value in 100ns since the unix epoch).
+	/// Data is `FILETIME` (64-bit value in 100ns since January 1, 1601).
 	/// No unit is stored but `is_utc` boolean is used during creation.
 	T_DATE,
 	/// Data is a 64-bit number, no units.
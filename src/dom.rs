@@ -119,6 +119,17 @@ el.state.visited = true;
 
 (after such call the element will match the `:visited` CSS selector)
 
+Text runs and comments between child elements are accessible via [`dom::Node`](struct.Node.html):
+
+```rust,no_run
+# use sciter::dom::{Element, Node};
+# let el = Element::from(::std::ptr::null_mut());
+// <p>Hello <b>big</b> world</p>
+let p = Node::from(&el);
+let mut hello = p.first_child().unwrap();
+hello.set_text("Goodbye ");
+```
+
 
 ## Getting and setting values of DOM elements.
 
@@ -160,19 +171,21 @@ if (var num = self.select("input[type=number]")) {
 
 use ::{_API};
 use capi::sctypes::*;
-use value::Value;
+use value::{FromValue, IntoArgs, Value, ScriptError};
+use error::Error;
 
 use capi::screquest::{REQUEST_PARAM, REQUEST_TYPE};
 use capi::scdef::RESOURCE_TYPE;
 use capi::scbehavior::{CLICK_REASON, BEHAVIOR_EVENTS, BEHAVIOR_EVENT_PARAMS};
 use utf::{store_astr, store_wstr, store_bstr};
 
-pub use capi::scdom::{SCDOM_RESULT, HELEMENT, SET_ELEMENT_HTML, ELEMENT_AREAS, ELEMENT_STATE_BITS};
+pub use capi::scdom::{SCDOM_RESULT, HELEMENT, HNODE, SET_ELEMENT_HTML, NODE_INS_TARGET, ELEMENT_AREAS, ELEMENT_STATE_BITS};
+pub use capi::scdom::SCITER_SCROLL_FLAGS;
 pub use dom::event::{EventHandler, EventReason};
 
 
 /// A specialized `Result` type for DOM operations.
-pub type Result<T> = ::std::result::Result<T, SCDOM_RESULT>;
+pub type Result<T> = ::std::result::Result<T, Error>;
 
 
 /// Initialize HELEMENT by nullptr.
@@ -182,23 +195,23 @@ macro_rules! HELEMENT {
 
 
 macro_rules! ok_or {
-	($rv:expr, $ok:ident) => {
+	($rv:expr, $ok:ident, $api:expr) => {
 		if $ok == SCDOM_RESULT::OK {
 			Ok($rv)
 		} else {
-			Err($ok)
+			Err(Error::Dom { api: $api, code: $ok })
 		}
 	};
+}
 
-	// for DOM access not_handled is ok
-	// for calling function operation_failed is also ok
-	($rv:expr, $ok:ident, $skip_not_handled:expr) => {
-		if $ok == SCDOM_RESULT::OK || ($ok == $skip_not_handled) {
-			Ok($rv)
-		} else {
-			Err($ok)
-		}
-	};
+
+/// The script result or the thrown error.
+fn script_result(api: &'static str, ok: SCDOM_RESULT, rv: Value) -> ::std::result::Result<Value, ScriptError> {
+	match ok {
+		SCDOM_RESULT::OK => ScriptError::check(rv),
+		_ if rv.is_undefined() => Err(ScriptError::failed(Error::Dom { api, code: ok })),
+		_ => Err(ScriptError::from_value(rv)),
+	}
 }
 
 
@@ -263,15 +276,15 @@ const DOM_UNWRAP_API_VERSION: u32 = 0x0000_0007;
 ///
 /// Since 4.4.3.26, perhaps.
 impl std::convert::TryFrom<Element> for Value {
-	type Error = SCDOM_RESULT;
+	type Error = Error;
 	fn try_from(e: Element) -> Result<Value> {
 		let mut v = Value::new();
-		let ok = if crate::api_version() >= DOM_UNWRAP_API_VERSION {
-			(_API.SciterElementWrap)(v.as_mut_ptr(), e.as_ptr())
+		let (ok, api) = if crate::api_version() >= DOM_UNWRAP_API_VERSION {
+			((_API.SciterElementWrap)(v.as_mut_ptr(), e.as_ptr()), "SciterElementWrap")
 		} else {
-			(_API.SciterGetExpando)(e.as_ptr(), v.as_ptr(), true as BOOL)
+			((_API.SciterGetExpando)(e.as_ptr(), v.as_ptr(), true as BOOL), "SciterGetExpando")
 		};
-		ok_or!(v, ok)
+		ok_or!(v, ok, api)
 	}
 }
 
@@ -299,6 +312,142 @@ impl crate::value::FromValue for Element {
 	}
 }
 
+/// Scroll state of the element, see [`Element::scroll_info`](struct.Element.html#method.scroll_info).
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ScrollInfo {
+	/// Current scroll position.
+	pub pos: POINT,
+	/// Visible area of the element.
+	pub view: RECT,
+	/// Size of the scrollable content.
+	pub content: SIZE,
+}
+
+impl ScrollInfo {
+	/// Maximal scroll position, i.e. the position where the end of content is visible.
+	pub fn max_pos(&self) -> POINT {
+		POINT {
+			x: ::std::cmp::max(0, self.content.cx - self.view.width()),
+			y: ::std::cmp::max(0, self.content.cy - self.view.height()),
+		}
+	}
+}
+
+/// Options of [`Element::scroll_to_view`](struct.Element.html#method.scroll_to_view).
+///
+/// ```rust,no_run
+/// # use sciter::dom::ScrollToView;
+/// # let item = sciter::dom::Element::from(::std::ptr::null_mut());
+/// item.scroll_to_view(ScrollToView { smooth: true, .. Default::default() }).unwrap();
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollToView {
+	/// Scroll the element to the top of the view.
+	pub toplevel: bool,
+	/// Animate the scrolling.
+	pub smooth: bool,
+}
+
+/// Position of a popup relative to its anchor, see [`Element::show_popup`](struct.Element.html#method.show_popup)
+/// and [`Element::show_popup_at`](struct.Element.html#method.show_popup_at).
+///
+/// The values follow the numeric keypad layout: the named point of the anchor's border box
+/// is where the popup is attached to, and the popup is placed on that side of it.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopupPlacement {
+	/// Below the anchor, aligned by its left edge.
+	BottomLeft = 1,
+	/// Below the anchor, centered.
+	Bottom = 2,
+	/// Below the anchor, aligned by its right edge.
+	BottomRight = 3,
+	/// On the left of the anchor.
+	Left = 4,
+	/// Over the center of the anchor.
+	Center = 5,
+	/// On the right of the anchor.
+	Right = 6,
+	/// Above the anchor, aligned by its left edge.
+	TopLeft = 7,
+	/// Above the anchor, centered.
+	Top = 8,
+	/// Above the anchor, aligned by its right edge.
+	TopRight = 9,
+}
+
+/// Which box of the element to get and relative to what, see [`Element::location`](struct.Element.html#method.location).
+///
+/// The origin is chosen by the constructor and the box by the modifiers; the content box is used by default.
+///
+/// ```
+/// use sciter::dom::{LocationKind, ELEMENT_AREAS};
+///
+/// let kind = LocationKind::view().border_box();
+/// assert_eq!(kind.bits(), ELEMENT_AREAS::VIEW_RELATIVE as u32 | ELEMENT_AREAS::BORDER_BOX as u32);
+/// assert_eq!(LocationKind::self_relative().bits(), ELEMENT_AREAS::self_content());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocationKind {
+	origin: u32,
+	area: u32,
+}
+
+impl LocationKind {
+	fn with_origin(origin: ELEMENT_AREAS) -> Self {
+		LocationKind { origin: origin as u32, area: ELEMENT_AREAS::CONTENT_BOX as u32 }
+	}
+
+	fn with_area(self, area: ELEMENT_AREAS) -> Self {
+		LocationKind { area: area as u32, .. self }
+	}
+
+	/// Relative to the Sciter window or to the nearest windowed container, e.g. popup window.
+	pub fn root() -> Self {
+		Self::with_origin(ELEMENT_AREAS::ROOT_RELATIVE)
+	}
+
+	/// Relative to the origin of the element itself.
+	pub fn self_relative() -> Self {
+		Self::with_origin(ELEMENT_AREAS::SELF_RELATIVE)
+	}
+
+	/// Relative to the immediate container.
+	pub fn container() -> Self {
+		Self::with_origin(ELEMENT_AREAS::CONTAINER_RELATIVE)
+	}
+
+	/// Relative to the view, i.e. the Sciter window.
+	pub fn view() -> Self {
+		Self::with_origin(ELEMENT_AREAS::VIEW_RELATIVE)
+	}
+
+	/// Content (inner) box.
+	pub fn content_box(self) -> Self {
+		self.with_area(ELEMENT_AREAS::CONTENT_BOX)
+	}
+
+	/// Content and paddings.
+	pub fn padding_box(self) -> Self {
+		self.with_area(ELEMENT_AREAS::PADDING_BOX)
+	}
+
+	/// Content, paddings and border.
+	pub fn border_box(self) -> Self {
+		self.with_area(ELEMENT_AREAS::BORDER_BOX)
+	}
+
+	/// Content, paddings, border and margins.
+	pub fn margin_box(self) -> Self {
+		self.with_area(ELEMENT_AREAS::MARGIN_BOX)
+	}
+
+	/// Raw [`ELEMENT_AREAS`](enum.ELEMENT_AREAS.html) flags for [`Element::get_location`](struct.Element.html#method.get_location).
+	pub fn bits(&self) -> u32 {
+		self.origin | self.area
+	}
+}
+
 impl Element {
 
 	//\name Creation
@@ -309,7 +458,7 @@ impl Element {
 		let tag = s2u!(tag);
 		let text = 0 as LPCWSTR;
 		let ok = (_API.SciterCreateElement)(tag.as_ptr(), text, &mut e.he);
-		ok_or!(e, ok)
+		ok_or!(e, ok, "SciterCreateElement")
 	}
 
 	/// Create new element as child of `parent`.
@@ -334,7 +483,7 @@ impl Element {
 		let tag = s2u!(tag);
 		let text = s2w!(text);
 		let ok = (_API.SciterCreateElement)(tag.as_ptr(), text.as_ptr(), &mut e.he);
-		ok_or!(e, ok)
+		ok_or!(e, ok, "SciterCreateElement")
 	}
 
 	/// Create new element with specified `type`, which is useful for controls and widgets (initially disconnected).
@@ -347,7 +496,7 @@ impl Element {
 			let r = e.set_attribute("type", el_type);
 			r.map(|_| e)
 		} else {
-			Err(ok)
+			Err(Error::Dom { api: "SciterCreateElement", code: ok })
 		}
 	}
 
@@ -360,9 +509,9 @@ impl Element {
 	/// but for now let's return an error for such cases.
 	///
 	/// https://github.com/sciter-sdk/rust-sciter/issues/27
-	fn forbid_null(e: Element) -> Result<Element> {
+	fn forbid_null(e: Element, api: &'static str) -> Result<Element> {
 		if e.he.is_null() {
-			Err(SCDOM_RESULT::OK_NOT_HANDLED)
+			Err(Error::Dom { api: api, code: SCDOM_RESULT::OK_NOT_HANDLED })
 		} else {
 			Ok(e)
 		}
@@ -374,7 +523,7 @@ impl Element {
 	pub fn from_window(hwnd: HWINDOW) -> Result<Element> {
 		let mut p = HELEMENT!();
 		let ok = (_API.SciterGetRootElement)(hwnd, &mut p);
-		ok_or!(Element::from(p), ok).and_then(Element::forbid_null)
+		ok_or!(Element::from(p), ok, "SciterGetRootElement").and_then(|e| Element::forbid_null(e, "SciterGetRootElement"))
 	}
 
 	/// Get focus DOM element of the Sciter document.
@@ -383,7 +532,7 @@ impl Element {
 	pub fn from_focus(hwnd: HWINDOW) -> Result<Element> {
 		let mut p = HELEMENT!();
 		let ok = (_API.SciterGetFocusElement)(hwnd, &mut p);
-		ok_or!(Element::from(p), ok).and_then(Element::forbid_null)
+		ok_or!(Element::from(p), ok, "SciterGetFocusElement").and_then(|e| Element::forbid_null(e, "SciterGetFocusElement"))
 	}
 
 	/// Get highlighted element.
@@ -392,7 +541,7 @@ impl Element {
 	pub fn from_highlighted(hwnd: HWINDOW) -> Result<Element> {
 		let mut p = HELEMENT!();
 		let ok = (_API.SciterGetHighlightedElement)(hwnd, &mut p);
-		ok_or!(Element::from(p), ok).and_then(Element::forbid_null)
+		ok_or!(Element::from(p), ok, "SciterGetHighlightedElement").and_then(|e| Element::forbid_null(e, "SciterGetHighlightedElement"))
 	}
 
 	/// Find DOM element of the Sciter document by coordinates.
@@ -401,7 +550,7 @@ impl Element {
 	pub fn from_point(hwnd: HWINDOW, pt: POINT) -> Result<Element> {
 		let mut p = HELEMENT!();
 		let ok = (_API.SciterFindElement)(hwnd, pt, &mut p);
-		ok_or!(Element::from(p), ok).and_then(Element::forbid_null)
+		ok_or!(Element::from(p), ok, "SciterFindElement").and_then(|e| Element::forbid_null(e, "SciterFindElement"))
 	}
 
 	/// Get element handle by its UID.
@@ -410,7 +559,7 @@ impl Element {
 	pub fn from_uid(hwnd: HWINDOW, uid: u32) -> Result<Element> {
 		let mut p = HELEMENT!();
 		let ok = (_API.SciterGetElementByUID)(hwnd, uid, &mut p);
-		ok_or!(Element::from(p), ok).and_then(Element::forbid_null)
+		ok_or!(Element::from(p), ok, "SciterGetElementByUID").and_then(|e| Element::forbid_null(e, "SciterGetElementByUID"))
 	}
 
 	#[doc(hidden)]
@@ -456,7 +605,7 @@ impl Element {
 	pub fn set_text(&mut self, text: &str) -> Result<()> {
 		let (s,n) = s2wn!(text);
 		let ok = (_API.SciterSetElementText)(self.he, s.as_ptr(), n);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterSetElementText")
 	}
 
 	/// Get html representation of the element as utf-8 bytes.
@@ -472,7 +621,7 @@ impl Element {
 			return self.clear();
 		}
 		let ok = (_API.SciterSetElementHtml)(self.he, html.as_ptr(), html.len() as UINT, how.unwrap_or(SET_ELEMENT_HTML::SIH_REPLACE_CONTENT) as UINT);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterSetElementHtml")
 	}
 
 	/// Get value of the element.
@@ -485,7 +634,7 @@ impl Element {
 	/// Set value of the element.
 	pub fn set_value<T: Into<Value>>(&mut self, val: T) -> Result<()> {
 		let ok = (_API.SciterSetValue)(self.he, val.into().as_cptr());
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterSetValue")
 	}
 
 	/// Checks if particular UI state bits are set in the element.
@@ -500,7 +649,7 @@ impl Element {
 	pub fn set_state(&mut self, set: ELEMENT_STATE_BITS, clear: Option<ELEMENT_STATE_BITS>, update: bool) -> Result<()> {
 		let clear = clear.unwrap_or(ELEMENT_STATE_BITS::STATE_NONE);
 		let ok = (_API.SciterSetElementState)(self.he, set as UINT, clear as UINT, update as BOOL);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterSetElementState")
 	}
 
 	/// Get `HWINDOW` of containing window.
@@ -513,27 +662,70 @@ impl Element {
 	/// Attach a native window to the element as a child.
 	pub fn attach_hwnd(&mut self, child: HWINDOW) -> Result<()> {
 		let ok = (_API.SciterAttachHwndToElement)(self.he, child);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterAttachHwndToElement")
 	}
 
 	/// Detach a child native window (if any) from the element.
 	pub fn detach_hwnd(&mut self) -> Result<()> {
 		let ok = (_API.SciterAttachHwndToElement)(self.he, 0 as HWINDOW);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterAttachHwndToElement")
 	}
 
-	/// Get bounding rectangle of the element. See the [`ELEMENT_AREAS`](enum.ELEMENT_AREAS.html) enum for `kind` flags.
+	/// Get bounding rectangle of the element. See the [`ELEMENT_AREAS`](enum.ELEMENT_AREAS.html) enum for `kind` flags
+	/// or [`location`](#method.location) for the typed variant.
 	pub fn get_location(&self, kind: u32) -> Result<RECT> {
 		let mut rc = RECT::default();
 		let ok = (_API.SciterGetElementLocation)(self.he, &mut rc as *mut _, kind as u32);
-		ok_or!(rc, ok)
+		ok_or!(rc, ok, "SciterGetElementLocation")
+	}
+
+	/// Get bounding rectangle of the element's box.
+	///
+	/// ```rust,no_run
+	/// # use sciter::dom::{Element, LocationKind};
+	/// # let el = Element::from(::std::ptr::null_mut());
+	/// let rc = el.location(LocationKind::container().margin_box()).unwrap();
+	/// ```
+	pub fn location(&self, kind: LocationKind) -> Result<RECT> {
+		self.get_location(kind.bits())
+	}
+
+	/// Whether the element and all of its parents are visible.
+	pub fn is_visible(&self) -> bool {
+		let mut visible = false as BOOL;
+		(_API.SciterIsElementVisible)(self.he, &mut visible);
+		return visible != 0;
+	}
+
+	/// Whether the element and all of its parents are enabled.
+	pub fn is_enabled(&self) -> bool {
+		let mut enabled = false as BOOL;
+		(_API.SciterIsElementEnabled)(self.he, &mut enabled);
+		return enabled != 0;
+	}
+
+	/// Get minimal and maximal intrinsic widths of the element's content.
+	///
+	/// The minimal width is the width of the widest unbreakable piece of content, the maximal one is
+	/// the width of content without any wrapping.
+	pub fn min_max_intrinsic_width(&self) -> Result<(i32, i32)> {
+		let (mut min, mut max) = (0, 0);
+		let ok = (_API.SciterGetElementIntrinsicWidths)(self.he, &mut min, &mut max);
+		ok_or!((min, max), ok, "SciterGetElementIntrinsicWidths")
+	}
+
+	/// Get intrinsic height of the element's content for the given width.
+	pub fn intrinsic_height(&self, for_width: i32) -> Result<i32> {
+		let mut height = 0;
+		let ok = (_API.SciterGetElementIntrinsicHeight)(self.he, for_width, &mut height);
+		ok_or!(height, ok, "SciterGetElementIntrinsicHeight")
 	}
 
 	/// Request data download for this element.
 	pub fn request_data(&self, url: &str, data_type: RESOURCE_TYPE, initiator: Option<HELEMENT>) -> Result<()> {
 		let url = s2w!(url);
 		let ok = (_API.SciterRequestElementData)(self.he, url.as_ptr(), data_type as u32, initiator.unwrap_or(HELEMENT!()));
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterRequestElementData")
 	}
 
 	/// Request HTML data download for this element.
@@ -548,7 +740,7 @@ impl Element {
 		let url = s2w!(url);
 		let no_params = ::std::ptr::null();
 		let ok = (_API.SciterHttpRequest)(self.he, url.as_ptr(), RESOURCE_TYPE::HTML as u32, REQUEST_TYPE::AsyncGet as u32, no_params, 0);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterHttpRequest")
 	}
 
 	/// Send an HTTP GET or POST request for the element.
@@ -583,7 +775,7 @@ impl Element {
 		}
 
 		let ok = (_API.SciterHttpRequest)(self.he, url.as_ptr(), data_type, method, call_params.as_ptr(), call_params.len() as u32);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterHttpRequest")
 	}
 
 	/// Sends sinking/bubbling event to the child/parent chain of the element.
@@ -592,7 +784,7 @@ impl Element {
 		let r = reason.unwrap_or(CLICK_REASON::SYNTHESIZED);
 		let s = source.unwrap_or(self.he);
 		let ok = (_API.SciterSendEvent)(self.he, code as u32, s, r as UINT_PTR, &mut handled);
-		ok_or!(handled != 0, ok)
+		ok_or!(handled != 0, ok, "SciterSendEvent")
 	}
 
 	/// Post asynchronously a sinking/bubbling event to the child/parent chain of the element.
@@ -600,7 +792,7 @@ impl Element {
 		let r = reason.unwrap_or(CLICK_REASON::SYNTHESIZED);
 		let s = source.unwrap_or(self.he);
 		let ok = (_API.SciterPostEvent)(self.he, code as u32, s, r as UINT_PTR);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterPostEvent")
 	}
 
 	/// Send or posts event to the child/parent chain of the element.
@@ -618,14 +810,14 @@ impl Element {
 			data.pack_to(&mut params.data);
 		}
 		let ok = (_API.SciterFireEvent)(&params, post as BOOL, &mut handled);
-		ok_or!(handled != 0, ok)
+		ok_or!(handled != 0, ok, "SciterFireEvent")
 	}
 
 	/// Send or posts event with specified params to the child/parent chain of the element.
 	pub fn fire_event_params(evt: &BEHAVIOR_EVENT_PARAMS, post: bool) -> Result<bool> {
 		let mut handled = false as BOOL;
 		let ok = (_API.SciterFireEvent)(evt, post as BOOL, &mut handled);
-		ok_or!(handled != 0, ok)
+		ok_or!(handled != 0, ok, "SciterFireEvent")
 	}
 
 	/// Broadcast a custom named event to all windows.
@@ -644,40 +836,56 @@ impl Element {
 		}
 		let mut handled = false as BOOL;
 		let ok = (_API.SciterFireEvent)(&params, post as BOOL, &mut handled);
-		ok_or!(handled != 0, ok)
+		ok_or!(handled != 0, ok, "SciterFireEvent")
 	}
 
 
 	/// Evaluate the given script in context of the element.
-	pub fn eval_script(&self, script: &str) -> Result<Value> {
+	///
+	/// Returns the script result value or the [`ScriptError`](../value/struct.ScriptError.html) thrown by script.
+	pub fn eval_script(&self, script: &str) -> ::std::result::Result<Value, ScriptError> {
 		let mut rv = Value::new();
 		let (s,n) = s2wn!(script);
 		let ok = (_API.SciterEvalElementScript)(self.he, s.as_ptr(), n, rv.as_ptr());
-		return ok_or!(rv, ok, SCDOM_RESULT::OPERATION_FAILED);
+		script_result("SciterEvalElementScript", ok, rv)
 	}
 
 	/// Call scripting function defined in the namespace of the element (a.k.a. global function).
 	///
 	/// You can use the [`make_args!(args...)`](../macro.make_args.html) macro which helps you
 	/// to construct script arguments from Rust types.
-	pub fn call_function(&self, name: &str, args: &[Value]) -> Result<Value> {
+	pub fn call_function(&self, name: &str, args: &[Value]) -> ::std::result::Result<Value, ScriptError> {
 		let mut rv = Value::new();
 		let name = s2u!(name);
 		let argv = Value::pack_args(args);
 		let ok = (_API.SciterCallScriptingFunction)(self.he, name.as_ptr(), argv.as_ptr(), argv.len() as UINT, rv.as_ptr());
-		return ok_or!(rv, ok, SCDOM_RESULT::OPERATION_FAILED);
+		script_result("SciterCallScriptingFunction", ok, rv)
+	}
+
+	/// Call scripting function defined in the namespace of the element with typed arguments and result.
+	///
+	/// See [`Host::call`](../host/struct.Host.html#method.call) for details.
+	///
+	/// ```rust,no_run
+	/// # use sciter::dom::Element;
+	/// # let root = Element::from(::std::ptr::null_mut());
+	/// let total: f64 = root.call("cart.total", ("USD",)).unwrap();
+	/// ```
+	pub fn call<A: IntoArgs, R: FromValue>(&self, name: &str, args: A) -> ::std::result::Result<R, Error> {
+		let rv = self.call_function(name, &args.into_args())?;
+		Ok(R::try_from_value(&rv)?)
 	}
 
 	/// Call scripting method defined for the element.
 	///
 	/// You can use the [`make_args!(args...)`](../macro.make_args.html) macro which helps you
 	/// to construct script arguments from Rust types.
-	pub fn call_method(&self, name: &str, args: &[Value]) -> Result<Value> {
+	pub fn call_method(&self, name: &str, args: &[Value]) -> ::std::result::Result<Value, ScriptError> {
 		let mut rv = Value::new();
 		let name = s2u!(name);
 		let argv = Value::pack_args(args);
 		let ok = (_API.SciterCallScriptingMethod)(self.he, name.as_ptr(), argv.as_ptr(), argv.len() as UINT, rv.as_ptr());
-		return ok_or!(rv, ok, SCDOM_RESULT::OPERATION_FAILED);
+		script_result("SciterCallScriptingMethod", ok, rv)
 	}
 
   /// Call behavior specific method.
@@ -709,7 +917,7 @@ impl Element {
         };
         let ok = call(&mut p as *mut _ as *mut METHOD_PARAMS);
         if ok != SCDOM_RESULT::OK {
-          return Err(ok);
+          return Err(Error::Dom { api: "SciterCallBehaviorMethod", code: ok });
         }
         *retv = Value::from(&p.value);
         ok
@@ -721,7 +929,7 @@ impl Element {
         };
         let ok = call(&mut p as *mut _ as *mut METHOD_PARAMS);
         if ok != SCDOM_RESULT::OK {
-          return Err(ok);
+          return Err(Error::Dom { api: "SciterCallBehaviorMethod", code: ok });
         }
         *retv = p.is_empty != 0;
         ok
@@ -732,7 +940,7 @@ impl Element {
         SCDOM_RESULT::INVALID_PARAMETER
       },
     };
-    ok_or!((), ok)
+    ok_or!((), ok, "SciterCallBehaviorMethod")
   }
 
 
@@ -775,7 +983,7 @@ impl Element {
 		let name = s2u!(name);
 		let value = s2w!(value);
 		let ok = (_API.SciterSetAttributeByName)(self.he, name.as_ptr(), value.as_ptr());
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterSetAttributeByName")
 	}
 
 	/// Remove attribute.
@@ -783,7 +991,7 @@ impl Element {
 		let name = s2u!(name);
 		let value = ::std::ptr::null();
 		let ok = (_API.SciterSetAttributeByName)(self.he, name.as_ptr(), value);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterSetAttributeByName")
 	}
 
 	/// Toggle attribute.
@@ -798,7 +1006,7 @@ impl Element {
 	/// Remove all attributes from the element.
 	pub fn clear_attributes(&mut self) -> Result<()> {
 		let ok = (_API.SciterClearAttributes)(self.he);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterClearAttributes")
 	}
 
 
@@ -817,7 +1025,7 @@ impl Element {
 		let name = s2u!(name);
 		let value = s2w!(value);
 		let ok = (_API.SciterSetStyleAttribute)(self.he, name.as_ptr(), value.as_ptr());
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterSetStyleAttribute")
 	}
 
 	//\name State methods
@@ -956,7 +1164,7 @@ impl Element {
 	/// Clear content of the element.
 	pub fn clear(&mut self) -> Result<()> {
 		let ok = (_API.SciterSetElementText)(self.he, ::std::ptr::null(), 0);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterSetElementText")
 	}
 
 	/// Create new element as copy of existing element.
@@ -976,7 +1184,7 @@ impl Element {
 	/// because the newly created `Element` is unusable before it will be inserted at DOM.
 	pub fn insert(&mut self, index: usize, child: &Element) -> Result<()> {
 		let ok = (_API.SciterInsertElement)(child.he, self.he, index as UINT);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterInsertElement")
 	}
 
 	/// Append element as last child of this element.
@@ -1005,7 +1213,7 @@ impl Element {
 	/// Take element out of its container (and DOM tree).
 	pub fn detach(&mut self) -> Result<()> {
 		let ok = (_API.SciterDetachElement)(self.he);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterDetachElement")
 	}
 
 	/// Take element out of its container (and DOM tree) and force destruction of all behaviors.
@@ -1013,13 +1221,61 @@ impl Element {
 		let mut p = HELEMENT!();
 		::std::mem::swap(&mut self.he, &mut p);
 		let ok = (_API.SciterDeleteElement)(p);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterDeleteElement")
 	}
 
 	/// Swap element positions.
 	pub fn swap(&mut self, other: &mut Element) -> Result<()> {
 		let ok = (_API.SciterSwapElements)(self.he, other.he);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterSwapElements")
+	}
+
+	/// Sort children of the element in the given `range` by the `compare` function.
+	///
+	/// The children are reordered in place by the engine, so they keep their event handlers and state.
+	///
+	/// ```rust,no_run
+	/// # let mut tbody = sciter::dom::Element::from(::std::ptr::null_mut());
+	/// // sort table rows by the text of the first cell, keeping the header row in place
+	/// tbody.sort_children(1.., |a, b| {
+	///   let a = a.first_child().map(|c| c.get_text()).unwrap_or_default();
+	///   let b = b.first_child().map(|c| c.get_text()).unwrap_or_default();
+	///   a.cmp(&b)
+	/// }).unwrap();
+	/// ```
+	pub fn sort_children<R, F>(&mut self, range: R, compare: F) -> Result<()>
+	where
+		R: ::std::ops::RangeBounds<usize>,
+		F: FnMut(&Element, &Element) -> ::std::cmp::Ordering,
+	{
+		extern "system" fn inner<F: FnMut(&Element, &Element) -> ::std::cmp::Ordering>(he1: HELEMENT, he2: HELEMENT, param: LPVOID) -> INT {
+			let p = param as *mut F;
+			let compare = unsafe { &mut *p };
+			let (a, b) = (Element::from(he1), Element::from(he2));
+			compare(&a, &b) as INT
+		}
+
+		use std::ops::Bound;
+		let count = self.children_count();
+		let first = match range.start_bound() {
+			Bound::Included(&n) => Some(n),
+			Bound::Excluded(&n) => n.checked_add(1),
+			Bound::Unbounded => Some(0),
+		};
+		let last = match range.end_bound() {
+			Bound::Included(&n) => n.checked_add(1),
+			Bound::Excluded(&n) => Some(n),
+			Bound::Unbounded => Some(count),
+		};
+		let (first, last) = match (first, last) {
+			(Some(first), Some(last)) if first <= last && last <= count => (first, last),
+			_ => return Err(Error::Dom { api: "SciterSortElements", code: SCDOM_RESULT::INVALID_PARAMETER }),
+		};
+
+		let mut compare = compare;
+		let param = &mut compare as *mut F as LPVOID;
+		let ok = (_API.SciterSortElements)(self.he, first as UINT, last as UINT, inner::<F>, param);
+		ok_or!((), ok, "SciterSortElements")
 	}
 
 	//\name Selectors
@@ -1047,7 +1303,7 @@ impl Element {
 		let ok = (_API.SciterSelectElements)(self.he, s.as_ptr(), inner::<T>, param as LPVOID);
     let handler = unsafe { Box::from_raw(param) };
 		if ok != SCDOM_RESULT::OK {
-			return Err(ok);
+			return Err(Error::Dom { api: "SciterSelectElements", code: ok });
 		}
 		return Ok(handler.result());
 	}
@@ -1058,7 +1314,7 @@ impl Element {
 		let s = s2u!(selector);
 		let ok = (_API.SciterSelectParent)(self.he, s.as_ptr(), 0, &mut p);
 		if ok != SCDOM_RESULT::OK {
-			return Err(ok);
+			return Err(Error::Dom { api: "SciterSelectParent", code: ok });
 		}
 		if p.is_null() { Ok(None) } else { Ok(Some(Element::from(p))) }
 	}
@@ -1079,12 +1335,86 @@ impl Element {
 
 	//\name Scroll methods:
 
+	/// Get scroll position, visible area and content size of the element.
+	pub fn scroll_info(&self) -> Result<ScrollInfo> {
+		let mut info = ScrollInfo::default();
+		let ok = (_API.SciterGetScrollInfo)(self.he, &mut info.pos, &mut info.view, &mut info.content);
+		ok_or!(info, ok, "SciterGetScrollInfo")
+	}
+
+	/// Set scroll position of the element, optionally with animation.
+	///
+	/// For example, to keep the tail of the content visible:
+	///
+	/// ```rust,no_run
+	/// # let log = sciter::dom::Element::from(::std::ptr::null_mut());
+	/// let info = log.scroll_info().unwrap();
+	/// log.set_scroll_pos(info.max_pos(), true).unwrap();
+	/// ```
+	pub fn set_scroll_pos(&self, pos: POINT, smooth: bool) -> Result<()> {
+		let ok = (_API.SciterSetScrollPos)(self.he, pos, smooth as BOOL);
+		ok_or!((), ok, "SciterSetScrollPos")
+	}
+
+	/// Scroll the containers of the element so that it becomes visible, see [`ScrollToView`](struct.ScrollToView.html).
+	pub fn scroll_to_view(&self, options: ScrollToView) -> Result<()> {
+		let mut flags = 0;
+		if options.toplevel {
+			flags |= SCITER_SCROLL_FLAGS::SCROLL_TO_TOP as UINT;
+		}
+		if options.smooth {
+			flags |= SCITER_SCROLL_FLAGS::SCROLL_SMOOTH as UINT;
+		}
+		let ok = (_API.SciterScrollToView)(self.he, flags);
+		ok_or!((), ok, "SciterScrollToView")
+	}
+
+	//\name Popup methods:
+
+	/// Show the element as a popup next to the `anchor` element.
+	///
+	/// Handlers receive [`popup_ready`](event/trait.EventHandler.html#method.popup_ready) when it is shown
+	/// and [`popup_dismissed`](event/trait.EventHandler.html#method.popup_dismissed) when it is closed.
+	///
+	/// ```rust,no_run
+	/// # use sciter::dom::{Element, PopupPlacement};
+	/// # let root = Element::from(::std::ptr::null_mut());
+	/// let button = root.find_first("button#menu").unwrap().unwrap();
+	/// let menu = root.find_first("menu#context").unwrap().unwrap();
+	/// menu.show_popup(&button, PopupPlacement::BottomLeft).unwrap();
+	/// ```
+	pub fn show_popup(&self, anchor: &Element, placement: PopupPlacement) -> Result<()> {
+		let ok = (_API.SciterShowPopup)(self.he, anchor.he, placement as UINT);
+		ok_or!((), ok, "SciterShowPopup")
+	}
+
+	/// Show the element as a popup at the given point of the window.
+	///
+	/// The `placement` is relative to `pos` as if it were an anchor of zero size.
+	///
+	/// ```rust,no_run
+	/// # use sciter::dom::{Element, PopupPlacement};
+	/// # use sciter::types::POINT;
+	/// # let menu = Element::from(::std::ptr::null_mut());
+	/// menu.show_popup_at(POINT { x: 100, y: 40 }, PopupPlacement::BottomRight).unwrap();
+	/// ```
+	pub fn show_popup_at(&self, pos: POINT, placement: PopupPlacement) -> Result<()> {
+		let ok = (_API.SciterShowPopupAt)(self.he, pos, placement as UINT);
+		ok_or!((), ok, "SciterShowPopupAt")
+	}
+
+	/// Close the popup shown by this element or by its content.
+	pub fn hide_popup(&self) -> Result<()> {
+		let ok = (_API.SciterHidePopup)(self.he);
+		ok_or!((), ok, "SciterHidePopup")
+	}
+
 	//\name Other methods:
 
 	/// Apply changes and refresh element area in its window.
 	pub fn update(&self, render_now: bool) -> Result<()> {
 		let ok = (_API.SciterUpdateElement)(self.he, render_now as BOOL);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterUpdateElement")
 	}
 
 	/// Refresh element area in its window.
@@ -1093,7 +1423,7 @@ impl Element {
 	pub fn refresh(&self) -> Result<()> {
 		let rect = self.get_location(ELEMENT_AREAS::self_content())?;
 		let ok = (_API.SciterRefreshElementArea)(self.he, rect);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterRefreshElementArea")
 	}
 
 	/// Start Timer for the element.
@@ -1103,14 +1433,14 @@ impl Element {
 	/// Note that timer events are not bubbling, so you need attach handler to the target element directly.
 	pub fn start_timer(&self, period_ms: u32, timer_id: u64) -> Result<()> {
 		let ok = (_API.SciterSetTimer)(self.he, period_ms as UINT, timer_id as ::capi::sctypes::UINT_PTR);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterSetTimer")
 	}
 
 	/// Stop Timer for the element.
 	pub fn stop_timer(&self, timer_id: u64) -> Result<()> {
 		if !self.he.is_null() {
 			let ok = (_API.SciterSetTimer)(self.he, 0, timer_id as ::capi::sctypes::UINT_PTR);
-			ok_or!((), ok)
+			ok_or!((), ok, "SciterSetTimer")
 		} else {
 			Ok(())
 		}
@@ -1123,14 +1453,14 @@ impl Element {
 		let ptr = Box::into_raw(boxed);	// dropped in `_event_handler_proc`
 		let token = ptr as usize as u64;
 		let ok = (_API.SciterAttachEventHandler)(self.he, ::eventhandler::_event_handler_proc::<Handler>, ptr as LPVOID);
-		ok_or!(token, ok)
+		ok_or!(token, ok, "SciterAttachEventHandler")
 	}
 
 	/// Detach your handler from the element. Handlers identified by `token` from `attach_handler()` result.
 	pub fn detach_handler<Handler: EventHandler>(&mut self, token: u64) -> Result<()> {
 		let ptr = token as usize as *mut Handler;
 		let ok = (_API.SciterDetachEventHandler)(self.he, ::eventhandler::_event_handler_proc::<Handler>, ptr as LPVOID);
-		ok_or!((), ok)
+		ok_or!((), ok, "SciterDetachEventHandler")
 	}
 }
 
@@ -1299,53 +1629,298 @@ impl<'a> ::std::iter::IntoIterator for &'a Element {
 }
 
 
+/// Type of a DOM node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeType {
+	/// Element, see [`Node::to_element`](struct.Node.html#method.to_element).
+	Element,
+	/// Text run.
+	Text,
+	/// Comment.
+	Comment,
+}
+
+/// DOM node wrapper: an element, a text run or a comment.
+///
+/// [`Element`](struct.Element.html) enumerates child elements only,
+/// nodes also give access to the text and comments between them.
+///
+/// ```rust,no_run
+/// # use sciter::dom::{Element, Node, NodeType};
+/// # let el = Element::from(::std::ptr::null_mut());
+/// let mut next = Node::from(&el).first_child();
+/// while let Some(mut node) = next {
+///   if node.node_type() == NodeType::Text {
+///     let text = node.get_text().to_uppercase();
+///     node.set_text(&text).unwrap();
+///   }
+///   next = node.next_sibling();
+/// }
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Node {
+	hn: HNODE,
+}
+
+/// `sciter::dom::Node` can be transferred across thread boundaries.
+unsafe impl Send for Node {}
+
+/// It is safe to share `sciter::dom::Node` between threads - underlaying API is thread-safe.
+unsafe impl Sync for Node {}
+
+impl From<HNODE> for Node {
+	/// Construct a Node object from an `HNODE` handle.
+	fn from(hn: HNODE) -> Self {
+		Node { hn: Node::use_or(hn) }
+	}
+}
+
+/// Get the node of the element.
+impl<'a> From<&'a Element> for Node {
+	fn from(e: &'a Element) -> Self {
+		let mut hn = ::std::ptr::null_mut();
+		(_API.SciterNodeCastFromElement)(e.as_ptr(), &mut hn);
+		Node::from(hn)
+	}
+}
+
+/// Get the node of the element.
+impl From<Element> for Node {
+	fn from(e: Element) -> Self {
+		Node::from(&e)
+	}
+}
+
+/// Get the element of the node, fails for text and comment nodes.
+impl std::convert::TryFrom<Node> for Element {
+	type Error = Error;
+	fn try_from(n: Node) -> Result<Element> {
+		n.to_element()
+	}
+}
+
+type NodeNavigation = extern "system" fn (HNODE, *mut HNODE) -> SCDOM_RESULT;
+
+impl Node {
+
+	//\name Creation
+
+	/// Create a new text node, it is disconnected initially from the DOM.
+	pub fn create_text(text: &str) -> Result<Node> {
+		let mut n = Node { hn: ::std::ptr::null_mut() };
+		let (s,len) = s2wn!(text);
+		let ok = (_API.SciterCreateTextNode)(s.as_ptr(), len, &mut n.hn);
+		ok_or!(n, ok, "SciterCreateTextNode")
+	}
+
+	/// Create a new comment node, it is disconnected initially from the DOM.
+	pub fn create_comment(text: &str) -> Result<Node> {
+		let mut n = Node { hn: ::std::ptr::null_mut() };
+		let (s,len) = s2wn!(text);
+		let ok = (_API.SciterCreateCommentNode)(s.as_ptr(), len, &mut n.hn);
+		ok_or!(n, ok, "SciterCreateCommentNode")
+	}
+
+	#[doc(hidden)]
+	fn use_or(hn: HNODE) -> HNODE {
+		let ok = (_API.SciterNodeAddRef)(hn);
+		if ok == SCDOM_RESULT::OK {
+			hn
+		} else {
+			::std::ptr::null_mut()
+		}
+	}
+
+
+	//\name Common methods
+
+	/// Access node pointer.
+	pub fn as_ptr(&self) -> HNODE {
+		self.hn
+	}
+
+	/// Type of the node.
+	pub fn node_type(&self) -> NodeType {
+		let mut t = 0u32;
+		(_API.SciterNodeType)(self.hn, &mut t);
+		match t {
+			1 => NodeType::Text,
+			2 => NodeType::Comment,
+			_ => NodeType::Element,
+		}
+	}
+
+	/// Whether the node is an element.
+	pub fn is_element(&self) -> bool {
+		self.node_type() == NodeType::Element
+	}
+
+	/// Whether the node is a text run.
+	pub fn is_text(&self) -> bool {
+		self.node_type() == NodeType::Text
+	}
+
+	/// Whether the node is a comment.
+	pub fn is_comment(&self) -> bool {
+		self.node_type() == NodeType::Comment
+	}
+
+	/// Get the element of the node.
+	///
+	/// Text and comment nodes are not elements, so this function will return an error for them.
+	pub fn to_element(&self) -> Result<Element> {
+		let mut he = HELEMENT!();
+		let ok = (_API.SciterNodeCastToElement)(self.hn, &mut he);
+		ok_or!(Element::from(he), ok, "SciterNodeCastToElement").and_then(|e| Element::forbid_null(e, "SciterNodeCastToElement"))
+	}
+
+	/// Get text of the node (inner text for elements).
+	pub fn get_text(&self) -> String {
+		let mut s = String::new();
+		(_API.SciterNodeGetText)(self.hn, store_wstr, &mut s as *mut String as LPVOID);
+		return s;
+	}
+
+	/// Set text of the node (inner text for elements).
+	pub fn set_text(&mut self, text: &str) -> Result<()> {
+		let (s,n) = s2wn!(text);
+		let ok = (_API.SciterNodeSetText)(self.hn, s.as_ptr(), n);
+		ok_or!((), ok, "SciterNodeSetText")
+	}
+
+
+	//\name DOM tree access
+
+	/// Get parent element.
+	pub fn parent(&self) -> Option<Element> {
+		let mut p = HELEMENT!();
+		(_API.SciterNodeParent)(self.hn, &mut p);
+		if p.is_null() {
+			None
+		} else {
+			Some(Element::from(p))
+		}
+	}
+
+	fn navigate(&self, f: NodeNavigation) -> Option<Node> {
+		let mut hn = ::std::ptr::null_mut();
+		let ok = f(self.hn, &mut hn);
+		if ok == SCDOM_RESULT::OK && !hn.is_null() {
+			Some(Node::from(hn))
+		} else {
+			None
+		}
+	}
+
+	/// Get next sibling node.
+	pub fn next_sibling(&self) -> Option<Node> {
+		self.navigate(_API.SciterNodeNextSibling)
+	}
+
+	/// Get previous sibling node.
+	pub fn prev_sibling(&self) -> Option<Node> {
+		self.navigate(_API.SciterNodePrevSibling)
+	}
+
+	/// Get first child node.
+	pub fn first_child(&self) -> Option<Node> {
+		self.navigate(_API.SciterNodeFirstChild)
+	}
+
+	/// Get last child node.
+	pub fn last_child(&self) -> Option<Node> {
+		self.navigate(_API.SciterNodeLastChild)
+	}
+
+	/// Get node's child at specified index.
+	pub fn nth_child(&self, index: usize) -> Option<Node> {
+		let mut hn = ::std::ptr::null_mut();
+		let ok = (_API.SciterNodeNthChild)(self.hn, index as UINT, &mut hn);
+		if ok == SCDOM_RESULT::OK && !hn.is_null() {
+			Some(Node::from(hn))
+		} else {
+			None
+		}
+	}
+
+	/// Get number of child nodes, including text and comments.
+	pub fn children_count(&self) -> usize {
+		let mut n = 0u32;
+		(_API.SciterNodeChildrenCount)(self.hn, &mut n);
+		return n as usize;
+	}
+
+	/// Insert the `node` relative to this one.
+	pub fn insert(&mut self, how: NODE_INS_TARGET, node: &Node) -> Result<()> {
+		let ok = (_API.SciterNodeInsert)(self.hn, how as UINT, node.hn);
+		ok_or!((), ok, "SciterNodeInsert")
+	}
+
+	/// Insert the `node` before this one.
+	pub fn insert_before(&mut self, node: &Node) -> Result<()> {
+		self.insert(NODE_INS_TARGET::NIT_BEFORE, node)
+	}
+
+	/// Insert the `node` after this one.
+	pub fn insert_after(&mut self, node: &Node) -> Result<()> {
+		self.insert(NODE_INS_TARGET::NIT_AFTER, node)
+	}
+
+	/// Append the `node` as the last child of this one.
+	pub fn append(&mut self, node: &Node) -> Result<()> {
+		self.insert(NODE_INS_TARGET::NIT_APPEND, node)
+	}
+
+	/// Insert the `node` as the first child of this one.
+	pub fn prepend(&mut self, node: &Node) -> Result<()> {
+		self.insert(NODE_INS_TARGET::NIT_PREPEND, node)
+	}
+
+	/// Remove the node from the DOM, it can be inserted again later.
+	pub fn detach(&mut self) -> Result<()> {
+		let ok = (_API.SciterNodeRemove)(self.hn, false as BOOL);
+		ok_or!((), ok, "SciterNodeRemove")
+	}
+
+	/// Remove the node from the DOM and destroy it.
+	pub fn destroy(&mut self) -> Result<()> {
+		let ok = (_API.SciterNodeRemove)(self.hn, true as BOOL);
+		ok_or!((), ok, "SciterNodeRemove")
+	}
+}
+
+/// Release node pointer.
+impl Drop for Node {
+	fn drop(&mut self) {
+		(_API.SciterNodeRelease)(self.hn);
+		self.hn = ::std::ptr::null_mut();
+	}
+}
+
+/// Increment reference count of the dom node.
+impl Clone for Node {
+	fn clone(&self) -> Self {
+		Node::from(self.hn)
+	}
+}
+
+
 /* Not implemented yet or not used APIs:
 
 SciterCallBehaviorMethod
 SciterCombineURL
 SciterControlGetType
-SciterGetElementIntrinsicHeight
-SciterGetElementIntrinsicWidths
-SciterGetElementLocation
 SciterGetElementNamespace
 SciterGetElementType
 SciterGetExpando
 SciterGetObject
-SciterGetScrollInfo
-SciterHidePopup
 SciterHttpRequest
-SciterIsElementEnabled
-SciterIsElementVisible
 SciterReleaseCapture
 SciterRequestElementData
-SciterScrollToView
 SciterSetCapture
 SciterSetHighlightedElement
-SciterSetScrollPos
-SciterShowPopup
-SciterShowPopupAt
-SciterSortElements
 SciterTraverseUIEvent
 
-SciterCreateCommentNode
-SciterCreateTextNode
-SciterNodeAddRef
-SciterNodeCastFromElement
-SciterNodeCastToElement
-SciterNodeChildrenCount
-SciterNodeFirstChild
-SciterNodeGetText
-SciterNodeInsert
-SciterNodeLastChild
-SciterNodeNextSibling
-SciterNodeNthChild
-SciterNodeParent
-SciterNodePrevSibling
-SciterNodeRelease
-SciterNodeRemove
-SciterNodeSetText
-SciterNodeType
-
 */
 
 pub mod event {
@@ -1570,6 +2145,16 @@ This way you can establish interaction between scipt and native code inside your
     /// but will be sent only for the root element (`<html>`).
 		fn document_close(&mut self, root: HELEMENT, target: HELEMENT) {}
 
+		/// Notification that the `popup` element has been measured and is about to be shown.
+    ///
+    /// **Subscription**: requires [`HANDLE_BEHAVIOR_EVENT`](enum.EVENT_GROUPS.html).
+		fn popup_ready(&mut self, root: HELEMENT, popup: HELEMENT) {}
+
+		/// Notification that the `popup` element has been closed.
+    ///
+    /// **Subscription**: requires [`HANDLE_BEHAVIOR_EVENT`](enum.EVENT_GROUPS.html).
+		fn popup_dismissed(&mut self, root: HELEMENT, popup: HELEMENT) {}
+
     /// Behavior method calls from script or other behaviors.
     ///
     /// Return `false` to skip this event.
@@ -0,0 +1,1353 @@
+//! In-process Sciter value API, enabled by the `emulated` feature.
+//!
+//! It implements the `Value*` functions of [`ISciterAPI`] in Rust, so `sciter::Value`
+//! can be used (and tested) without the Sciter shared library.
+//! All other functions of the API abort the process with a message.
+//!
+//! Reference types (strings, bytes, arrays, maps and native functions) are stored
+//! in the `VALUE::d` field as `Arc` pointers, so copying a value just increases its reference count.
+
+#![allow(non_snake_case)]
+#![allow(deprecated)]
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+use std::sync::{Arc, Mutex};
+
+use capi::sctypes::*;
+use capi::scdef::*;
+use capi::scdom::*;
+use capi::scvalue::*;
+use capi::scapi::ISciterAPI;
+use capi::sctiscript::{HVM, tiscript_value, tiscript_native_interface};
+use capi::scbehavior::*;
+use capi::scgraphics::SciterGraphicsAPI;
+use capi::screquest::{SciterRequestAPI, HREQUEST, REQUEST_PARAM};
+use capi::scmsg::{SCITER_X_MSG};
+use capi::scom::{som_asset_t, som_atom_t};
+
+
+/// Reported engine version, `4.4.8.23`.
+const VERSION: (UINT, UINT) = (0x0004_0004, 0x0008_0017);
+
+/// Returns the emulated API table.
+pub fn SciterAPI() -> *const ISciterAPI {
+	&API as *const ISciterAPI
+}
+
+
+/* Value storage */
+
+// Heap data of reference types.
+enum Data {
+	String(Vec<WCHAR>),
+	Bytes(Vec<BYTE>),
+	Array(Mutex<Vec<VALUE>>),
+	Map(Mutex<Vec<(VALUE, VALUE)>>),
+	Functor(Functor),
+}
+
+struct Functor {
+	invoke: NATIVE_FUNCTOR_INVOKE,
+	release: NATIVE_FUNCTOR_RELEASE,
+	tag: LPVOID,
+}
+
+// Like `sciter::Value`, which holds it.
+unsafe impl Send for Functor {}
+unsafe impl Sync for Functor {}
+
+impl Drop for Functor {
+	fn drop(&mut self) {
+		(self.release)(self.tag);
+	}
+}
+
+impl Drop for Data {
+	fn drop(&mut self) {
+		match self {
+			Data::Array(items) => {
+				for item in items.get_mut().unwrap().iter_mut() {
+					clear(item);
+				}
+			},
+			Data::Map(items) => {
+				for (key, item) in items.get_mut().unwrap().iter_mut() {
+					clear(key);
+					clear(item);
+				}
+			},
+			_ => {},
+		}
+	}
+}
+
+fn is_reference(t: VALUE_TYPE) -> bool {
+	use capi::scvalue::VALUE_TYPE::*;
+	matches!(t, T_STRING | T_BYTES | T_ARRAY | T_MAP | T_FUNCTION)
+}
+
+fn data(v: &VALUE) -> Option<&Data> {
+	if is_reference(v.t) && v.d != 0 {
+		Some(unsafe { &*(v.d as usize as *const Data) })
+	} else {
+		None
+	}
+}
+
+fn make(t: VALUE_TYPE, u: UINT, d: UINT64) -> VALUE {
+	VALUE { t: t, u: u, d: d }
+}
+
+fn make_ref(t: VALUE_TYPE, u: UINT, data: Data) -> VALUE {
+	let ptr = Arc::into_raw(Arc::new(data));
+	make(t, u, ptr as usize as UINT64)
+}
+
+fn make_str(s: &str, u: UINT) -> VALUE {
+	let mut chars: Vec<WCHAR> = s.encode_utf16().collect();
+	chars.push(0);
+	make_ref(VALUE_TYPE::T_STRING, u, Data::String(chars))
+}
+
+fn copy(v: &VALUE) -> VALUE {
+	if data(v).is_some() {
+		unsafe { Arc::increment_strong_count(v.d as usize as *const Data) };
+	}
+	v.clone()
+}
+
+fn clear(v: &mut VALUE) {
+	if data(v).is_some() {
+		unsafe { Arc::decrement_strong_count(v.d as usize as *const Data) };
+	}
+	*v = VALUE::default();
+}
+
+fn assign(dst: &mut VALUE, src: VALUE) {
+	clear(dst);
+	*dst = src;
+}
+
+fn to_string(chars: &[WCHAR]) -> String {
+	String::from_utf16_lossy(&chars[..chars.len() - 1])
+}
+
+fn as_str(v: &VALUE) -> Option<String> {
+	match data(v) {
+		Some(Data::String(chars)) => Some(to_string(chars)),
+		_ => None,
+	}
+}
+
+fn as_float(v: &VALUE) -> f64 {
+	f64::from_bits(v.d)
+}
+
+fn as_int(v: &VALUE) -> INT {
+	v.d as u32 as INT
+}
+
+// Copies of the (key, value) pairs of a container.
+fn snapshot(v: &VALUE) -> Vec<(VALUE, VALUE)> {
+	match data(v) {
+		Some(Data::Array(items)) => items.lock().unwrap().iter().enumerate()
+			.map(|(i, item)| (make(VALUE_TYPE::T_INT, 0, i as UINT64), copy(item)))
+			.collect(),
+		Some(Data::Map(items)) => items.lock().unwrap().iter()
+			.map(|(key, item)| (copy(key), copy(item)))
+			.collect(),
+		_ => Vec::new(),
+	}
+}
+
+fn release(items: Vec<(VALUE, VALUE)>) {
+	for (mut key, mut item) in items {
+		clear(&mut key);
+		clear(&mut item);
+	}
+}
+
+// Structural equality.
+fn equal(a: &VALUE, b: &VALUE) -> bool {
+	use capi::scvalue::VALUE_TYPE::*;
+	if a.t != b.t {
+		return false;
+	}
+	match a.t {
+		T_UNDEFINED | T_NULL => a.u == b.u,
+		T_FLOAT | T_LENGTH | T_DURATION | T_ANGLE => a.u == b.u && as_float(a) == as_float(b),
+		T_STRING | T_BYTES | T_ARRAY | T_MAP | T_FUNCTION => {
+			if a.d == b.d {
+				return true;
+			}
+			match (data(a), data(b)) {
+				(Some(Data::String(x)), Some(Data::String(y))) => a.u == b.u && x == y,
+				(Some(Data::Bytes(x)), Some(Data::Bytes(y))) => x == y,
+				(Some(Data::Array(_)), Some(Data::Array(_))) => {
+					let (x, y) = (snapshot(a), snapshot(b));
+					let eq = x.len() == y.len() && x.iter().zip(y.iter()).all(|(x, y)| equal(&x.1, &y.1));
+					release(x);
+					release(y);
+					eq
+				},
+				(Some(Data::Map(_)), Some(Data::Map(_))) => {
+					let (x, y) = (snapshot(a), snapshot(b));
+					let eq = x.len() == y.len() && x.iter().all(|(key, item)| {
+						y.iter().any(|(other, value)| same_key(key, other) && equal(item, value))
+					});
+					release(x);
+					release(y);
+					eq
+				},
+				_ => false,
+			}
+		},
+		_ => a.u == b.u && a.d == b.d,
+	}
+}
+
+// Map keys are matched by text, so a symbol key matches a string one.
+fn same_key(a: &VALUE, b: &VALUE) -> bool {
+	match (as_str(a), as_str(b)) {
+		(Some(x), Some(y)) => x == y,
+		_ => equal(a, b),
+	}
+}
+
+
+/* Value API */
+
+extern "system" fn ValueInit(pval: *mut VALUE) -> VALUE_RESULT {
+	unsafe { *pval = VALUE::default() };
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueClear(pval: *mut VALUE) -> VALUE_RESULT {
+	clear(unsafe { &mut *pval });
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueCompare(pval1: *const VALUE, pval2: *const VALUE) -> VALUE_RESULT {
+	if equal(unsafe { &*pval1 }, unsafe { &*pval2 }) {
+		VALUE_RESULT::OK_TRUE
+	} else {
+		VALUE_RESULT::OK
+	}
+}
+
+extern "system" fn ValueCopy(pdst: *mut VALUE, psrc: *const VALUE) -> VALUE_RESULT {
+	let src = copy(unsafe { &*psrc });
+	assign(unsafe { &mut *pdst }, src);
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueIsolate(_pdst: *mut VALUE) -> VALUE_RESULT {
+	// there are no script objects here
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueType(pval: *const VALUE, pType: *mut UINT, pUnits: *mut UINT) -> VALUE_RESULT {
+	let v = unsafe { &*pval };
+	unsafe {
+		*pType = v.t as UINT;
+		*pUnits = v.u;
+	}
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueStringData(pval: *const VALUE, pChars: *mut LPCWSTR, pNumChars: *mut UINT) -> VALUE_RESULT {
+	match data(unsafe { &*pval }) {
+		Some(Data::String(chars)) => {
+			unsafe {
+				*pChars = chars.as_ptr();
+				*pNumChars = (chars.len() - 1) as UINT;
+			}
+			VALUE_RESULT::OK
+		},
+		_ => VALUE_RESULT::INCOMPATIBLE_TYPE,
+	}
+}
+
+extern "system" fn ValueStringDataSet(pval: *mut VALUE, chars: LPCWSTR, numChars: UINT, units: UINT) -> VALUE_RESULT {
+	let mut s: Vec<WCHAR> = if chars.is_null() {
+		Vec::new()
+	} else {
+		unsafe { ::std::slice::from_raw_parts(chars, numChars as usize) }.to_vec()
+	};
+	s.push(0);
+	assign(unsafe { &mut *pval }, make_ref(VALUE_TYPE::T_STRING, units, Data::String(s)));
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueIntData(pval: *const VALUE, pData: *mut INT) -> VALUE_RESULT {
+	use capi::scvalue::VALUE_TYPE::*;
+	let v = unsafe { &*pval };
+	match v.t {
+		T_BOOL | T_INT | T_COLOR => {
+			unsafe { *pData = as_int(v) };
+			VALUE_RESULT::OK
+		},
+		_ => VALUE_RESULT::INCOMPATIBLE_TYPE,
+	}
+}
+
+extern "system" fn ValueIntDataSet(pval: *mut VALUE, data: INT, vtype: UINT, units: UINT) -> VALUE_RESULT {
+	let v = if vtype == VALUE_TYPE::T_ARRAY as UINT {
+		let items = (0..data.max(0)).map(|_| VALUE::default()).collect();
+		make_ref(VALUE_TYPE::T_ARRAY, units, Data::Array(Mutex::new(items)))
+	} else if vtype == VALUE_TYPE::T_MAP as UINT {
+		make_ref(VALUE_TYPE::T_MAP, units, Data::Map(Mutex::new(Vec::new())))
+	} else if let Some(t) = scalar_type(vtype) {
+		make(t, units, data as u32 as UINT64)
+	} else {
+		return VALUE_RESULT::BAD_PARAMETER;
+	};
+	assign(unsafe { &mut *pval }, v);
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueInt64Data(pval: *const VALUE, pData: *mut INT64) -> VALUE_RESULT {
+	use capi::scvalue::VALUE_TYPE::*;
+	let v = unsafe { &*pval };
+	match v.t {
+		T_DATE | T_CURRENCY | T_RANGE | T_ASSET => {
+			unsafe { *pData = v.d as INT64 };
+			VALUE_RESULT::OK
+		},
+		_ => VALUE_RESULT::INCOMPATIBLE_TYPE,
+	}
+}
+
+extern "system" fn ValueInt64DataSet(pval: *mut VALUE, data: INT64, vtype: UINT, units: UINT) -> VALUE_RESULT {
+	let t = match scalar_type(vtype) {
+		Some(t) => t,
+		None => return VALUE_RESULT::BAD_PARAMETER,
+	};
+	// the date unit is an `is_utc` flag used during creation only
+	let units = if t == VALUE_TYPE::T_DATE { 0 } else { units };
+	assign(unsafe { &mut *pval }, make(t, units, data as UINT64));
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueFloatData(pval: *const VALUE, pData: *mut FLOAT_VALUE) -> VALUE_RESULT {
+	use capi::scvalue::VALUE_TYPE::*;
+	let v = unsafe { &*pval };
+	let f = match v.t {
+		T_INT => f64::from(as_int(v)),
+		T_FLOAT | T_LENGTH | T_DURATION | T_ANGLE => as_float(v),
+		_ => return VALUE_RESULT::INCOMPATIBLE_TYPE,
+	};
+	unsafe { *pData = f };
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueFloatDataSet(pval: *mut VALUE, data: FLOAT_VALUE, vtype: UINT, units: UINT) -> VALUE_RESULT {
+	let t = match scalar_type(vtype) {
+		Some(t) => t,
+		None => return VALUE_RESULT::BAD_PARAMETER,
+	};
+	assign(unsafe { &mut *pval }, make(t, units, data.to_bits()));
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueBinaryData(pval: *const VALUE, pBytes: *mut LPCBYTE, pnBytes: *mut UINT) -> VALUE_RESULT {
+	match data(unsafe { &*pval }) {
+		Some(Data::Bytes(bytes)) => {
+			unsafe {
+				*pBytes = bytes.as_ptr();
+				*pnBytes = bytes.len() as UINT;
+			}
+			VALUE_RESULT::OK
+		},
+		_ => VALUE_RESULT::INCOMPATIBLE_TYPE,
+	}
+}
+
+extern "system" fn ValueBinaryDataSet(pval: *mut VALUE, pBytes: LPCBYTE, nBytes: UINT, _vtype: UINT, units: UINT) -> VALUE_RESULT {
+	let bytes = if pBytes.is_null() {
+		Vec::new()
+	} else {
+		unsafe { ::std::slice::from_raw_parts(pBytes, nBytes as usize) }.to_vec()
+	};
+	assign(unsafe { &mut *pval }, make_ref(VALUE_TYPE::T_BYTES, units, Data::Bytes(bytes)));
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueElementsCount(pval: *const VALUE, pn: *mut INT) -> VALUE_RESULT {
+	let n = match data(unsafe { &*pval }) {
+		Some(Data::Array(items)) => items.lock().unwrap().len(),
+		Some(Data::Map(items)) => items.lock().unwrap().len(),
+		_ => 0,
+	};
+	unsafe { *pn = n as INT };
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueNthElementValue(pval: *const VALUE, n: INT, pretval: *mut VALUE) -> VALUE_RESULT {
+	let item = match data(unsafe { &*pval }) {
+		Some(Data::Array(items)) => items.lock().unwrap().get(n as usize).map(copy),
+		Some(Data::Map(items)) => items.lock().unwrap().get(n as usize).map(|pair| copy(&pair.1)),
+		_ => None,
+	};
+	let ok = if item.is_some() { VALUE_RESULT::OK } else { VALUE_RESULT::BAD_PARAMETER };
+	assign(unsafe { &mut *pretval }, item.unwrap_or_default());
+	ok
+}
+
+extern "system" fn ValueNthElementValueSet(pval: *mut VALUE, n: INT, pval_to_set: *const VALUE) -> VALUE_RESULT {
+	if n < 0 {
+		return VALUE_RESULT::BAD_PARAMETER;
+	}
+	let me = unsafe { &mut *pval };
+	let mut item = copy(unsafe { &*pval_to_set });
+	match data(me) {
+		Some(Data::Array(_)) | Some(Data::Map(_)) => {},
+		_ => {
+			// convert to array
+			assign(me, make_ref(VALUE_TYPE::T_ARRAY, 0, Data::Array(Mutex::new(Vec::new()))));
+		}
+	}
+	let n = n as usize;
+	match data(me) {
+		Some(Data::Array(items)) => {
+			let mut items = items.lock().unwrap();
+			while items.len() <= n {
+				items.push(VALUE::default());
+			}
+			::std::mem::swap(&mut items[n], &mut item);
+		},
+		Some(Data::Map(items)) => {
+			let mut items = items.lock().unwrap();
+			if let Some(pair) = items.get_mut(n) {
+				::std::mem::swap(&mut pair.1, &mut item);
+			} else {
+				drop(items);
+				clear(&mut item);
+				return VALUE_RESULT::BAD_PARAMETER;
+			}
+		},
+		_ => unreachable!(),
+	}
+	clear(&mut item);
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueNthElementKey(pval: *const VALUE, n: INT, pretval: *mut VALUE) -> VALUE_RESULT {
+	let key = match data(unsafe { &*pval }) {
+		Some(Data::Map(items)) => items.lock().unwrap().get(n as usize).map(|pair| copy(&pair.0)),
+		_ => None,
+	};
+	let ok = if key.is_some() { VALUE_RESULT::OK } else { VALUE_RESULT::BAD_PARAMETER };
+	assign(unsafe { &mut *pretval }, key.unwrap_or_default());
+	ok
+}
+
+extern "system" fn ValueEnumElements(pval: *const VALUE, penum: KeyValueCallback, param: LPVOID) -> VALUE_RESULT {
+	let items = snapshot(unsafe { &*pval });
+	for (key, item) in &items {
+		if penum(param, key, item) == 0 {
+			break;
+		}
+	}
+	release(items);
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueSetValueToKey(pval: *mut VALUE, pkey: *const VALUE, pval_to_set: *const VALUE) -> VALUE_RESULT {
+	let me = unsafe { &mut *pval };
+	let key = unsafe { &*pkey };
+	if let Some(Data::Array(_)) = data(me) {
+		if key.t == VALUE_TYPE::T_INT {
+			return ValueNthElementValueSet(pval, as_int(key), pval_to_set);
+		}
+	}
+	let mut item = copy(unsafe { &*pval_to_set });
+	if !matches!(data(me), Some(Data::Map(_))) {
+		// convert to map
+		assign(me, make_ref(VALUE_TYPE::T_MAP, 0, Data::Map(Mutex::new(Vec::new()))));
+	}
+	if let Some(Data::Map(items)) = data(me) {
+		let mut items = items.lock().unwrap();
+		if let Some(pair) = items.iter_mut().find(|pair| same_key(&pair.0, key)) {
+			::std::mem::swap(&mut pair.1, &mut item);
+		} else {
+			items.push((copy(key), item));
+			return VALUE_RESULT::OK;
+		}
+	}
+	clear(&mut item);
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueGetValueOfKey(pval: *const VALUE, pkey: *const VALUE, pretval: *mut VALUE) -> VALUE_RESULT {
+	let key = unsafe { &*pkey };
+	let item = match data(unsafe { &*pval }) {
+		Some(Data::Map(items)) => items.lock().unwrap().iter().find(|pair| same_key(&pair.0, key)).map(|pair| copy(&pair.1)),
+		Some(Data::Array(items)) if key.t == VALUE_TYPE::T_INT => items.lock().unwrap().get(as_int(key) as usize).map(copy),
+		_ => None,
+	};
+	assign(unsafe { &mut *pretval }, item.unwrap_or_default());
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueToString(pval: *mut VALUE, how: VALUE_STRING_CVT_TYPE) -> VALUE_RESULT {
+	let me = unsafe { &mut *pval };
+	let s = match how {
+		VALUE_STRING_CVT_TYPE::SIMPLE => {
+			if me.t == VALUE_TYPE::T_STRING {
+				return VALUE_RESULT::OK;
+			}
+			json::write(me)
+		},
+		VALUE_STRING_CVT_TYPE::JSON_MAP if me.t == VALUE_TYPE::T_MAP => {
+			let s = json::write(me);
+			s[1..s.len() - 1].to_owned()
+		},
+		_ => json::write(me),
+	};
+	assign(me, make_str(&s, 0));
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueFromString(pval: *mut VALUE, str: LPCWSTR, strLength: UINT, how: VALUE_STRING_CVT_TYPE) -> UINT {
+	let chars = if str.is_null() { &[] } else { unsafe { ::std::slice::from_raw_parts(str, strLength as usize) } };
+	let text: Vec<char> = ::std::char::decode_utf16(chars.iter().cloned())
+		.map(|c| c.unwrap_or(::std::char::REPLACEMENT_CHARACTER))
+		.collect();
+	let parsed = match how {
+		VALUE_STRING_CVT_TYPE::SIMPLE => Ok(json::parse_simple(&text)),
+		VALUE_STRING_CVT_TYPE::JSON_MAP => json::parse_map(&text),
+		_ => json::parse(&text),
+	};
+	match parsed {
+		Ok(v) => {
+			assign(unsafe { &mut *pval }, v);
+			0
+		},
+		Err(left) => left as UINT,
+	}
+}
+
+extern "system" fn ValueInvoke(pval: *const VALUE, _pthis: *mut VALUE, argc: UINT, argv: *const VALUE, pretval: *mut VALUE, _url: LPCWSTR) -> VALUE_RESULT {
+	match data(unsafe { &*pval }) {
+		Some(Data::Functor(f)) => {
+			(f.invoke)(f.tag, argc, argv, pretval);
+			VALUE_RESULT::OK
+		},
+		_ => VALUE_RESULT::INCOMPATIBLE_TYPE,
+	}
+}
+
+extern "system" fn ValueNativeFunctorSet(pval: *mut VALUE, pinvoke: NATIVE_FUNCTOR_INVOKE, prelease: NATIVE_FUNCTOR_RELEASE, tag: LPVOID) -> VALUE_RESULT {
+	let f = Functor { invoke: pinvoke, release: prelease, tag: tag };
+	assign(unsafe { &mut *pval }, make_ref(VALUE_TYPE::T_FUNCTION, 0, Data::Functor(f)));
+	VALUE_RESULT::OK
+}
+
+extern "system" fn ValueIsNativeFunctor(pval: *const VALUE) -> BOOL {
+	matches!(data(unsafe { &*pval }), Some(Data::Functor(_))) as BOOL
+}
+
+// Types that are stored in the `VALUE::d` field directly.
+fn scalar_type(vtype: UINT) -> Option<VALUE_TYPE> {
+	use capi::scvalue::VALUE_TYPE::*;
+	let types = [T_UNDEFINED, T_NULL, T_BOOL, T_INT, T_FLOAT, T_DATE, T_CURRENCY, T_LENGTH,
+		T_RANGE, T_DURATION, T_ANGLE, T_COLOR, T_ASSET];
+	types.iter().cloned().find(|&t| t as UINT == vtype)
+}
+
+
+/* JSON conversion */
+
+mod json {
+	use super::*;
+
+	/// Length units and their CSS names.
+	pub const LENGTH_UNITS: &[(UINT, &str)] = &[
+		(1, "em"), (2, "ex"), (3, "%"), (4, "*"), (7, "px"), (8, "in"), (9, "cm"),
+		(10, "mm"), (11, "pt"), (12, "pc"), (13, "dip"),
+	];
+
+	pub fn write(v: &VALUE) -> String {
+		let mut out = String::new();
+		write_to(v, &mut out);
+		out
+	}
+
+	fn write_str(s: &str, out: &mut String) {
+		out.push('"');
+		for c in s.chars() {
+			match c {
+				'"' => out.push_str("\\\""),
+				'\\' => out.push_str("\\\\"),
+				'\n' => out.push_str("\\n"),
+				'\r' => out.push_str("\\r"),
+				'\t' => out.push_str("\\t"),
+				c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+				c => out.push(c),
+			}
+		}
+		out.push('"');
+	}
+
+	fn write_float(f: f64, out: &mut String) {
+		if f.is_finite() {
+			out.push_str(&format!("{:?}", f));
+		} else {
+			out.push_str("null");
+		}
+	}
+
+	fn write_to(v: &VALUE, out: &mut String) {
+		use capi::scvalue::VALUE_TYPE::*;
+		match v.t {
+			T_NULL => out.push_str("null"),
+			T_BOOL => out.push_str(if as_int(v) != 0 { "true" } else { "false" }),
+			T_INT => out.push_str(&as_int(v).to_string()),
+			T_FLOAT => write_float(as_float(v), out),
+			T_STRING => write_str(&as_str(v).unwrap_or_default(), out),
+			T_BYTES => {
+				if let Some(Data::Bytes(bytes)) = data(v) {
+					let items: Vec<String> = bytes.iter().map(|b| b.to_string()).collect();
+					out.push('[');
+					out.push_str(&items.join(","));
+					out.push(']');
+				}
+			},
+			T_ARRAY => {
+				let items = snapshot(v);
+				out.push('[');
+				for (i, (_, item)) in items.iter().enumerate() {
+					if i > 0 {
+						out.push(',');
+					}
+					write_to(item, out);
+				}
+				out.push(']');
+				release(items);
+			},
+			T_MAP => {
+				let items = snapshot(v);
+				out.push('{');
+				for (i, (key, item)) in items.iter().enumerate() {
+					if i > 0 {
+						out.push(',');
+					}
+					match as_str(key) {
+						Some(s) => write_str(&s, out),
+						None => write_str(&write(key), out),
+					}
+					out.push(':');
+					write_to(item, out);
+				}
+				out.push('}');
+				release(items);
+			},
+			T_CURRENCY => {
+				let n = v.d as INT64;
+				let sign = if n < 0 { "-" } else { "" };
+				out.push_str(&format!("{}{}.{:04}", sign, (n / 10000).abs(), (n % 10000).abs()));
+			},
+			T_DATE => write_str(&format_date(v.d as INT64), out),
+			T_LENGTH => {
+				let unit = LENGTH_UNITS.iter().find(|u| u.0 == v.u).map_or("", |u| u.1);
+				write_str(&format!("{}{}", as_float(v), unit), out);
+			},
+			T_DURATION => write_str(&format!("{}s", as_float(v)), out),
+			T_ANGLE => write_str(&format!("{}rad", as_float(v)), out),
+			T_COLOR => write_str(&format!("#{:08x}", (as_int(v) as u32).swap_bytes()), out),
+			T_RANGE => write_str(&format!("{}..{}", v.d as u32 as INT, (v.d >> 32) as u32 as INT), out),
+			T_FUNCTION => write_str("[function]", out),
+			T_ASSET => write_str("[asset]", out),
+			_ => out.push_str("undefined"),
+		}
+	}
+
+	/// `FILETIME` (100ns since 1601-01-01) as an ISO 8601 UTC string.
+	fn format_date(ft: INT64) -> String {
+		const EPOCH_DIFF: i64 = 11_644_473_600; // 1601-01-01 .. 1970-01-01 in seconds
+		let ms = ft.div_euclid(10_000);
+		let secs = ms.div_euclid(1000) - EPOCH_DIFF;
+		let (days, rem) = (secs.div_euclid(86400), secs.rem_euclid(86400));
+
+		// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+		let z = days + 719_468;
+		let era = z.div_euclid(146_097);
+		let doe = z - era * 146_097;
+		let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+		let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+		let mp = (5 * doy + 2) / 153;
+		let d = doy - (153 * mp + 2) / 5 + 1;
+		let m = if mp < 10 { mp + 3 } else { mp - 9 };
+		let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+
+		format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+			y, m, d, rem / 3600, rem / 60 % 60, rem % 60, ms.rem_euclid(1000))
+	}
+
+
+	/// `CVT_SIMPLE`: a terminal value (number, boolean, null) or a string as is.
+	pub fn parse_simple(text: &[char]) -> VALUE {
+		let mut p = Parser { text: text, pos: 0 };
+		p.skip_ws();
+		if let Ok(v) = p.terminal() {
+			p.skip_ws();
+			if p.pos == text.len() {
+				return v;
+			}
+			let mut v = v;
+			clear(&mut v);
+		}
+		let s: String = text.iter().collect();
+		make_str(&s, 0)
+	}
+
+	/// `CVT_JSON_MAP`: key/value pairs without the enclosing braces.
+	pub fn parse_map(text: &[char]) -> Result<VALUE, usize> {
+		let mut p = Parser { text: text, pos: 0 };
+		p.map_body(None).and_then(|v| p.finish(v))
+	}
+
+	/// `CVT_JSON_LITERAL`: JSON with unquoted keys, single quoted strings,
+	/// and an optional map without braces at the top level (`one: 1, two: 2`).
+	pub fn parse(text: &[char]) -> Result<VALUE, usize> {
+		let mut p = Parser { text: text, pos: 0 };
+		p.skip_ws();
+		if p.pos == text.len() {
+			return Ok(VALUE::default());
+		}
+		if p.is_map_body() {
+			return parse_map(text);
+		}
+		p.value().and_then(|v| p.finish(v))
+	}
+
+	struct Parser<'a> {
+		text: &'a [char],
+		pos: usize,
+	}
+
+	impl<'a> Parser<'a> {
+		fn peek(&self) -> Option<char> {
+			self.text.get(self.pos).cloned()
+		}
+
+		// Number of chars left unparsed.
+		fn error<T>(&self) -> Result<T, usize> {
+			Err((self.text.len() - self.pos).max(1))
+		}
+
+		fn skip_ws(&mut self) {
+			while let Some(c) = self.peek() {
+				if !c.is_whitespace() {
+					break;
+				}
+				self.pos += 1;
+			}
+		}
+
+		fn expect(&mut self, c: char) -> Result<(), usize> {
+			self.skip_ws();
+			if self.peek() == Some(c) {
+				self.pos += 1;
+				Ok(())
+			} else {
+				self.error()
+			}
+		}
+
+		fn finish(&mut self, mut v: VALUE) -> Result<VALUE, usize> {
+			self.skip_ws();
+			if self.pos == self.text.len() {
+				Ok(v)
+			} else {
+				clear(&mut v);
+				self.error()
+			}
+		}
+
+		fn is_map_body(&mut self) -> bool {
+			let start = self.pos;
+			let mut key = self.key();
+			self.skip_ws();
+			let is_map = key.is_ok() && self.peek() == Some(':');
+			if let Ok(ref mut key) = key {
+				clear(key);
+			}
+			self.pos = start;
+			is_map
+		}
+
+		fn is_ident(c: char, first: bool) -> bool {
+			c == '_' || c == '$' || c.is_alphabetic() || (!first && (c.is_ascii_digit() || c == '-'))
+		}
+
+		fn ident(&mut self) -> Option<String> {
+			let start = self.pos;
+			while let Some(c) = self.peek() {
+				if !Self::is_ident(c, self.pos == start) {
+					break;
+				}
+				self.pos += 1;
+			}
+			if self.pos > start {
+				Some(self.text[start..self.pos].iter().collect())
+			} else {
+				None
+			}
+		}
+
+		fn key(&mut self) -> Result<VALUE, usize> {
+			self.skip_ws();
+			match self.peek() {
+				Some('"') | Some('\'') => self.string().map(|s| make_str(&s, 0)),
+				Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+				_ => match self.ident() {
+					Some(s) => Ok(make_str(&s, VALUE_UNIT_TYPE_STRING::SYMBOL as UINT)),
+					None => self.error(),
+				},
+			}
+		}
+
+		fn map_body(&mut self, close: Option<char>) -> Result<VALUE, usize> {
+			let mut items: Vec<(VALUE, VALUE)> = Vec::new();
+			let result = loop {
+				self.skip_ws();
+				if self.peek() == close {
+					break Ok(());
+				}
+				let key = match self.key() {
+					Ok(key) => key,
+					Err(e) => break Err(e),
+				};
+				let item = self.expect(':').and_then(|_| self.value());
+				match item {
+					Ok(item) => items.push((key, item)),
+					Err(e) => {
+						release(vec![(key, VALUE::default())]);
+						break Err(e);
+					}
+				}
+				self.skip_ws();
+				if self.peek() == Some(',') {
+					self.pos += 1;
+				} else if self.peek() != close {
+					break self.error();
+				}
+			};
+			match result {
+				Ok(()) => Ok(make_ref(VALUE_TYPE::T_MAP, 0, Data::Map(Mutex::new(items)))),
+				Err(e) => {
+					release(items);
+					Err(e)
+				}
+			}
+		}
+
+		fn array(&mut self) -> Result<VALUE, usize> {
+			let mut items: Vec<VALUE> = Vec::new();
+			let result = loop {
+				self.skip_ws();
+				if self.peek() == Some(']') {
+					break Ok(());
+				}
+				match self.value() {
+					Ok(item) => items.push(item),
+					Err(e) => break Err(e),
+				}
+				self.skip_ws();
+				if self.peek() == Some(',') {
+					self.pos += 1;
+				} else if self.peek() != Some(']') {
+					break self.error();
+				}
+			};
+			match result {
+				Ok(()) => {
+					self.pos += 1;
+					Ok(make_ref(VALUE_TYPE::T_ARRAY, 0, Data::Array(Mutex::new(items))))
+				},
+				Err(e) => {
+					for mut item in items {
+						clear(&mut item);
+					}
+					Err(e)
+				}
+			}
+		}
+
+		fn value(&mut self) -> Result<VALUE, usize> {
+			self.skip_ws();
+			match self.peek() {
+				Some('{') => {
+					self.pos += 1;
+					let v = self.map_body(Some('}'))?;
+					self.pos += 1;
+					Ok(v)
+				},
+				Some('[') => {
+					self.pos += 1;
+					self.array()
+				},
+				Some('"') | Some('\'') => self.string().map(|s| make_str(&s, 0)),
+				_ => self.terminal(),
+			}
+		}
+
+		fn terminal(&mut self) -> Result<VALUE, usize> {
+			match self.peek() {
+				Some(c) if c == '-' || c == '+' || c.is_ascii_digit() => self.number(),
+				_ => {
+					let start = self.pos;
+					match self.ident().as_deref() {
+						Some("null") => Ok(make(VALUE_TYPE::T_NULL, 0, 0)),
+						Some("undefined") => Ok(VALUE::default()),
+						Some("true") => Ok(make(VALUE_TYPE::T_BOOL, 0, 1)),
+						Some("false") => Ok(make(VALUE_TYPE::T_BOOL, 0, 0)),
+						_ => {
+							self.pos = start;
+							self.error()
+						}
+					}
+				}
+			}
+		}
+
+		fn number(&mut self) -> Result<VALUE, usize> {
+			let start = self.pos;
+			let mut is_float = false;
+			while let Some(c) = self.peek() {
+				match c {
+					'0'..='9' | '-' | '+' => {},
+					'.' | 'e' | 'E' => is_float = true,
+					_ => break,
+				}
+				self.pos += 1;
+			}
+			let s: String = self.text[start..self.pos].iter().collect();
+			if !is_float {
+				if let Ok(i) = s.parse::<i32>() {
+					return Ok(make(VALUE_TYPE::T_INT, 0, i as u32 as UINT64));
+				}
+			}
+			match s.parse::<f64>() {
+				Ok(f) => Ok(make(VALUE_TYPE::T_FLOAT, 0, f.to_bits())),
+				Err(_) => {
+					self.pos = start;
+					self.error()
+				}
+			}
+		}
+
+		fn string(&mut self) -> Result<String, usize> {
+			let quote = self.peek().unwrap();
+			self.pos += 1;
+			let mut s = String::new();
+			loop {
+				let c = match self.peek() {
+					Some(c) => c,
+					None => return self.error(),
+				};
+				self.pos += 1;
+				if c == quote {
+					return Ok(s);
+				}
+				if c != '\\' {
+					s.push(c);
+					continue;
+				}
+				let c = match self.peek() {
+					Some(c) => c,
+					None => return self.error(),
+				};
+				self.pos += 1;
+				match c {
+					'b' => s.push('\u{8}'),
+					'f' => s.push('\u{c}'),
+					'n' => s.push('\n'),
+					'r' => s.push('\r'),
+					't' => s.push('\t'),
+					'u' => {
+						let hex: String = self.text.iter().skip(self.pos).take(4).collect();
+						let code = match u32::from_str_radix(&hex, 16) {
+							Ok(code) if hex.len() == 4 => code,
+							_ => return self.error(),
+						};
+						self.pos += 4;
+						s.push(::std::char::from_u32(code).unwrap_or(::std::char::REPLACEMENT_CHARACTER));
+					},
+					c => s.push(c),
+				}
+			}
+		}
+	}
+}
+
+
+/* Engine */
+
+extern "system" fn SciterVersion(major: BOOL) -> UINT {
+	if major != 0 { VERSION.0 } else { VERSION.1 }
+}
+
+// There is no UI thread here, so posted promise settlements are delivered immediately.
+extern "system" fn SciterPostCallback(_hwnd: HWINDOW, wparam: UINT_PTR, lparam: UINT_PTR, _timeoutms: UINT) -> UINT_PTR {
+	::value::settle_posted(wparam, lparam) as UINT_PTR
+}
+
+fn unsupported(name: &str) -> ! {
+	panic!("`{}` is not available in the emulated Sciter API.", name);
+}
+
+// The rest of the API is not available.
+macro_rules! unsupported {
+	($($name:ident ( $($arg:ty),* ) -> $ret:ty;)*) => {
+		$(
+			extern "system" fn $name($(_: $arg),*) -> $ret {
+				unsupported(stringify!($name))
+			}
+		)*
+	};
+}
+
+unsupported! {
+	SciterClassName() -> LPCWSTR;
+	SciterDataReady(HWINDOW, LPCWSTR, LPCBYTE, UINT) -> BOOL;
+	SciterDataReadyAsync(HWINDOW, LPCWSTR, LPCBYTE, UINT, HREQUEST) -> BOOL;
+	SciterProc(HWINDOW, UINT, WPARAM, LPARAM) -> LRESULT;
+	SciterProcND(HWINDOW, UINT, WPARAM, LPARAM, *mut BOOL) -> LRESULT;
+	SciterLoadFile(HWINDOW, LPCWSTR) -> BOOL;
+	SciterLoadHtml(HWINDOW, LPCBYTE, UINT, LPCWSTR) -> BOOL;
+	SciterSetCallback(HWINDOW, SciterHostCallback, LPVOID) -> VOID;
+	SciterSetMasterCSS(LPCBYTE, UINT) -> BOOL;
+	SciterAppendMasterCSS(LPCBYTE, UINT) -> BOOL;
+	SciterSetCSS(HWINDOW, LPCBYTE, UINT, LPCWSTR, LPCWSTR) -> BOOL;
+	SciterSetMediaType(HWINDOW, LPCWSTR) -> BOOL;
+	SciterSetMediaVars(HWINDOW, *const VALUE) -> BOOL;
+	SciterGetMinWidth(HWINDOW) -> UINT;
+	SciterGetMinHeight(HWINDOW, UINT) -> UINT;
+	SciterCall(HWINDOW, LPCSTR, UINT, *const VALUE, *mut VALUE) -> BOOL;
+	SciterEval(HWINDOW, LPCWSTR, UINT, *mut VALUE) -> BOOL;
+	SciterUpdateWindow(HWINDOW) -> VOID;
+	SciterTranslateMessage(LPMSG) -> BOOL;
+	SciterSetOption(HWINDOW, SCITER_RT_OPTIONS, UINT_PTR) -> BOOL;
+	SciterGetPPI(HWINDOW, *mut UINT, *mut UINT) -> VOID;
+	SciterGetViewExpando(HWINDOW, *mut VALUE) -> BOOL;
+	SciterRenderD2D(HWINDOW, *mut ID2D1RenderTarget) -> BOOL;
+	SciterD2DFactory(*mut *mut ID2D1Factory) -> BOOL;
+	SciterDWFactory(*mut *mut IDWriteFactory) -> BOOL;
+	SciterGraphicsCaps(LPUINT) -> BOOL;
+	SciterSetHomeURL(HWINDOW, LPCWSTR) -> BOOL;
+	SciterCreateNSView(LPRECT) -> HWINDOW;
+	SciterCreateWidget(LPRECT) -> HWINDOW;
+	SciterCreateWindow(UINT, LPCRECT, *const SciterWindowDelegate, LPVOID, HWINDOW) -> HWINDOW;
+	SciterSetupDebugOutput(HWINDOW, LPVOID, DEBUG_OUTPUT_PROC) -> ();
+	Sciter_UseElement(HELEMENT) -> SCDOM_RESULT;
+	Sciter_UnuseElement(HELEMENT) -> SCDOM_RESULT;
+	SciterGetRootElement(HWINDOW, *mut HELEMENT) -> SCDOM_RESULT;
+	SciterGetFocusElement(HWINDOW, *mut HELEMENT) -> SCDOM_RESULT;
+	SciterFindElement(HWINDOW, POINT, *mut HELEMENT) -> SCDOM_RESULT;
+	SciterGetChildrenCount(HELEMENT, *mut UINT) -> SCDOM_RESULT;
+	SciterGetNthChild(HELEMENT, UINT, *mut HELEMENT) -> SCDOM_RESULT;
+	SciterGetParentElement(HELEMENT, *mut HELEMENT) -> SCDOM_RESULT;
+	SciterGetElementHtmlCB(HELEMENT, BOOL, LPCBYTE_RECEIVER, LPVOID) -> SCDOM_RESULT;
+	SciterGetElementTextCB(HELEMENT, LPCWSTR_RECEIVER, LPVOID) -> SCDOM_RESULT;
+	SciterSetElementText(HELEMENT, LPCWSTR, UINT) -> SCDOM_RESULT;
+	SciterGetAttributeCount(HELEMENT, LPUINT) -> SCDOM_RESULT;
+	SciterGetNthAttributeNameCB(HELEMENT, UINT, LPCSTR_RECEIVER, LPVOID) -> SCDOM_RESULT;
+	SciterGetNthAttributeValueCB(HELEMENT, UINT, LPCWSTR_RECEIVER, LPVOID) -> SCDOM_RESULT;
+	SciterGetAttributeByNameCB(HELEMENT, LPCSTR, LPCWSTR_RECEIVER, LPVOID) -> SCDOM_RESULT;
+	SciterSetAttributeByName(HELEMENT, LPCSTR, LPCWSTR) -> SCDOM_RESULT;
+	SciterClearAttributes(HELEMENT) -> SCDOM_RESULT;
+	SciterGetElementIndex(HELEMENT, LPUINT) -> SCDOM_RESULT;
+	SciterGetElementType(HELEMENT, *mut LPCSTR) -> SCDOM_RESULT;
+	SciterGetElementTypeCB(HELEMENT, LPCSTR_RECEIVER, LPVOID) -> SCDOM_RESULT;
+	SciterGetStyleAttributeCB(HELEMENT, LPCSTR, LPCWSTR_RECEIVER, LPVOID) -> SCDOM_RESULT;
+	SciterSetStyleAttribute(HELEMENT, LPCSTR, LPCWSTR) -> SCDOM_RESULT;
+	SciterGetElementLocation(HELEMENT, LPRECT, UINT) -> SCDOM_RESULT;
+	SciterScrollToView(HELEMENT, UINT) -> SCDOM_RESULT;
+	SciterUpdateElement(HELEMENT, BOOL) -> SCDOM_RESULT;
+	SciterRefreshElementArea(HELEMENT, RECT) -> SCDOM_RESULT;
+	SciterSetCapture(HELEMENT) -> SCDOM_RESULT;
+	SciterReleaseCapture(HELEMENT) -> SCDOM_RESULT;
+	SciterGetElementHwnd(HELEMENT, *mut HWINDOW, BOOL) -> SCDOM_RESULT;
+	SciterCombineURL(HELEMENT, LPWSTR, UINT) -> SCDOM_RESULT;
+	SciterSelectElements(HELEMENT, LPCSTR, SciterElementCallback, LPVOID) -> SCDOM_RESULT;
+	SciterSelectElementsW(HELEMENT, LPCWSTR, SciterElementCallback, LPVOID) -> SCDOM_RESULT;
+	SciterSelectParent(HELEMENT, LPCSTR, UINT, *mut HELEMENT) -> SCDOM_RESULT;
+	SciterSelectParentW(HELEMENT, LPCWSTR, UINT, *mut HELEMENT) -> SCDOM_RESULT;
+	SciterSetElementHtml(HELEMENT, *const BYTE, UINT, UINT) -> SCDOM_RESULT;
+	SciterGetElementUID(HELEMENT, *mut UINT) -> SCDOM_RESULT;
+	SciterGetElementByUID(HWINDOW, UINT, *mut HELEMENT) -> SCDOM_RESULT;
+	SciterShowPopup(HELEMENT, HELEMENT, UINT) -> SCDOM_RESULT;
+	SciterShowPopupAt(HELEMENT, POINT, UINT) -> SCDOM_RESULT;
+	SciterHidePopup(HELEMENT) -> SCDOM_RESULT;
+	SciterGetElementState(HELEMENT, *mut UINT) -> SCDOM_RESULT;
+	SciterSetElementState(HELEMENT, UINT, UINT, BOOL) -> SCDOM_RESULT;
+	SciterCreateElement(LPCSTR, LPCWSTR, *mut HELEMENT) -> SCDOM_RESULT;
+	SciterCloneElement(HELEMENT, *mut HELEMENT) -> SCDOM_RESULT;
+	SciterInsertElement(HELEMENT, HELEMENT, UINT) -> SCDOM_RESULT;
+	SciterDetachElement(HELEMENT) -> SCDOM_RESULT;
+	SciterDeleteElement(HELEMENT) -> SCDOM_RESULT;
+	SciterSetTimer(HELEMENT, UINT, UINT_PTR) -> SCDOM_RESULT;
+	SciterDetachEventHandler(HELEMENT, ElementEventProc, LPVOID) -> SCDOM_RESULT;
+	SciterAttachEventHandler(HELEMENT, ElementEventProc, LPVOID) -> SCDOM_RESULT;
+	SciterWindowAttachEventHandler(HWINDOW, ElementEventProc, LPVOID, UINT) -> SCDOM_RESULT;
+	SciterWindowDetachEventHandler(HWINDOW, ElementEventProc, LPVOID) -> SCDOM_RESULT;
+	SciterSendEvent(HELEMENT, UINT, HELEMENT, UINT_PTR, *mut BOOL) -> SCDOM_RESULT;
+	SciterPostEvent(HELEMENT, UINT, HELEMENT, UINT_PTR) -> SCDOM_RESULT;
+	SciterCallBehaviorMethod(HELEMENT, *const METHOD_PARAMS) -> SCDOM_RESULT;
+	SciterRequestElementData(HELEMENT, LPCWSTR, UINT, HELEMENT) -> SCDOM_RESULT;
+	SciterHttpRequest(HELEMENT, LPCWSTR, UINT, UINT, *const REQUEST_PARAM, UINT) -> SCDOM_RESULT;
+	SciterGetScrollInfo(HELEMENT, LPPOINT, LPRECT, LPSIZE) -> SCDOM_RESULT;
+	SciterSetScrollPos(HELEMENT, POINT, BOOL) -> SCDOM_RESULT;
+	SciterGetElementIntrinsicWidths(HELEMENT, *mut INT, *mut INT) -> SCDOM_RESULT;
+	SciterGetElementIntrinsicHeight(HELEMENT, INT, *mut INT) -> SCDOM_RESULT;
+	SciterIsElementVisible(HELEMENT, *mut BOOL) -> SCDOM_RESULT;
+	SciterIsElementEnabled(HELEMENT, *mut BOOL) -> SCDOM_RESULT;
+	SciterSortElements(HELEMENT, UINT, UINT, ELEMENT_COMPARATOR, LPVOID) -> SCDOM_RESULT;
+	SciterSwapElements(HELEMENT, HELEMENT) -> SCDOM_RESULT;
+	SciterTraverseUIEvent(UINT, LPVOID, *mut BOOL) -> SCDOM_RESULT;
+	SciterCallScriptingMethod(HELEMENT, LPCSTR, *const VALUE, UINT, *mut VALUE) -> SCDOM_RESULT;
+	SciterCallScriptingFunction(HELEMENT, LPCSTR, *const VALUE, UINT, *mut VALUE) -> SCDOM_RESULT;
+	SciterEvalElementScript(HELEMENT, LPCWSTR, UINT, *mut VALUE) -> SCDOM_RESULT;
+	SciterAttachHwndToElement(HELEMENT, HWINDOW) -> SCDOM_RESULT;
+	SciterControlGetType(HELEMENT, *mut UINT) -> SCDOM_RESULT;
+	SciterGetValue(HELEMENT, *mut VALUE) -> SCDOM_RESULT;
+	SciterSetValue(HELEMENT, *const VALUE) -> SCDOM_RESULT;
+	SciterGetExpando(HELEMENT, *mut VALUE, BOOL) -> SCDOM_RESULT;
+	SciterGetObject(HELEMENT, *mut tiscript_value, BOOL) -> SCDOM_RESULT;
+	SciterGetElementNamespace(HELEMENT, *mut tiscript_value) -> SCDOM_RESULT;
+	SciterGetHighlightedElement(HWINDOW, *mut HELEMENT) -> SCDOM_RESULT;
+	SciterSetHighlightedElement(HWINDOW, HELEMENT) -> SCDOM_RESULT;
+	SciterNodeAddRef(HNODE) -> SCDOM_RESULT;
+	SciterNodeRelease(HNODE) -> SCDOM_RESULT;
+	SciterNodeCastFromElement(HELEMENT, *mut HNODE) -> SCDOM_RESULT;
+	SciterNodeCastToElement(HNODE, *mut HELEMENT) -> SCDOM_RESULT;
+	SciterNodeFirstChild(HNODE, *mut HNODE) -> SCDOM_RESULT;
+	SciterNodeLastChild(HNODE, *mut HNODE) -> SCDOM_RESULT;
+	SciterNodeNextSibling(HNODE, *mut HNODE) -> SCDOM_RESULT;
+	SciterNodePrevSibling(HNODE, *mut HNODE) -> SCDOM_RESULT;
+	SciterNodeParent(HNODE, *mut HELEMENT) -> SCDOM_RESULT;
+	SciterNodeNthChild(HNODE, UINT, *mut HNODE) -> SCDOM_RESULT;
+	SciterNodeChildrenCount(HNODE, *mut UINT) -> SCDOM_RESULT;
+	SciterNodeType(HNODE, *mut UINT) -> SCDOM_RESULT;
+	SciterNodeGetText(HNODE, LPCWSTR_RECEIVER, LPVOID) -> SCDOM_RESULT;
+	SciterNodeSetText(HNODE, LPCWSTR, UINT) -> SCDOM_RESULT;
+	SciterNodeInsert(HNODE, UINT, HNODE) -> SCDOM_RESULT;
+	SciterNodeRemove(HNODE, BOOL) -> SCDOM_RESULT;
+	SciterCreateTextNode(LPCWSTR, UINT, *mut HNODE) -> SCDOM_RESULT;
+	SciterCreateCommentNode(LPCWSTR, UINT, *mut HNODE) -> SCDOM_RESULT;
+	TIScriptAPI() -> * mut tiscript_native_interface;
+	SciterGetVM(HWINDOW) -> HVM;
+	Sciter_v2V(HVM, tiscript_value, *mut VALUE, BOOL) -> BOOL;
+	Sciter_V2v(HVM, *const VALUE, *mut tiscript_value) -> BOOL;
+	SciterOpenArchive(LPCBYTE, UINT) -> HSARCHIVE;
+	SciterGetArchiveItem(HSARCHIVE, LPCWSTR, *mut LPCBYTE, *mut UINT) -> BOOL;
+	SciterCloseArchive(HSARCHIVE) -> BOOL;
+	SciterFireEvent(*const BEHAVIOR_EVENT_PARAMS, BOOL, *mut BOOL) -> SCDOM_RESULT;
+	SciterGetCallbackParam(HWINDOW) -> LPVOID;
+	GetSciterGraphicsAPI() -> * const SciterGraphicsAPI;
+	GetSciterRequestAPI() -> * const SciterRequestAPI;
+	SciterCreateOnDirectXWindow(HWINDOW, *mut IDXGISwapChain) -> BOOL;
+	SciterRenderOnDirectXWindow(HWINDOW, HELEMENT, BOOL) -> BOOL;
+	SciterRenderOnDirectXTexture(HWINDOW, HELEMENT, *mut IDXGISurface) -> BOOL;
+	SciterProcX(HWINDOW, *const SCITER_X_MSG) -> BOOL;
+	SciterAtomValue(LPCSTR) -> som_atom_t;
+	SciterAtomNameCB(som_atom_t, LPCSTR_RECEIVER, LPVOID) -> BOOL;
+	SciterSetGlobalAsset(*mut som_asset_t) -> BOOL;
+	SciterGetElementAsset(HELEMENT, som_atom_t, *mut *mut som_asset_t) -> SCDOM_RESULT;
+	SciterSetVariable(HWINDOW, LPCSTR, *const VALUE) -> SCDOM_RESULT;
+	SciterGetVariable(HWINDOW, LPCSTR, *mut VALUE) -> SCDOM_RESULT;
+	SciterElementUnwrap(*const VALUE, *mut HELEMENT) -> SCDOM_RESULT;
+	SciterElementWrap(*mut VALUE, HELEMENT) -> SCDOM_RESULT;
+	SciterNodeUnwrap(*const VALUE, *mut HNODE) -> SCDOM_RESULT;
+	SciterNodeWrap(*mut VALUE, HNODE) -> SCDOM_RESULT;
+}
+
+static API: ISciterAPI = ISciterAPI {
+	version: if cfg!(feature = "windowless") { 0x0001_0009 } else { 0x0000_0009 },
+
+	SciterClassName,
+	SciterVersion,
+	SciterDataReady,
+	SciterDataReadyAsync,
+	SciterProc,
+	SciterProcND,
+	SciterLoadFile,
+	SciterLoadHtml,
+	SciterSetCallback,
+	SciterSetMasterCSS,
+	SciterAppendMasterCSS,
+	SciterSetCSS,
+	SciterSetMediaType,
+	SciterSetMediaVars,
+	SciterGetMinWidth,
+	SciterGetMinHeight,
+	SciterCall,
+	SciterEval,
+	SciterUpdateWindow,
+	SciterTranslateMessage,
+	SciterSetOption,
+	SciterGetPPI,
+	SciterGetViewExpando,
+	SciterRenderD2D,
+	SciterD2DFactory,
+	SciterDWFactory,
+	SciterGraphicsCaps,
+	SciterSetHomeURL,
+	SciterCreateNSView,
+	SciterCreateWidget,
+	SciterCreateWindow,
+	SciterSetupDebugOutput,
+	Sciter_UseElement,
+	Sciter_UnuseElement,
+	SciterGetRootElement,
+	SciterGetFocusElement,
+	SciterFindElement,
+	SciterGetChildrenCount,
+	SciterGetNthChild,
+	SciterGetParentElement,
+	SciterGetElementHtmlCB,
+	SciterGetElementTextCB,
+	SciterSetElementText,
+	SciterGetAttributeCount,
+	SciterGetNthAttributeNameCB,
+	SciterGetNthAttributeValueCB,
+	SciterGetAttributeByNameCB,
+	SciterSetAttributeByName,
+	SciterClearAttributes,
+	SciterGetElementIndex,
+	SciterGetElementType,
+	SciterGetElementTypeCB,
+	SciterGetStyleAttributeCB,
+	SciterSetStyleAttribute,
+	SciterGetElementLocation,
+	SciterScrollToView,
+	SciterUpdateElement,
+	SciterRefreshElementArea,
+	SciterSetCapture,
+	SciterReleaseCapture,
+	SciterGetElementHwnd,
+	SciterCombineURL,
+	SciterSelectElements,
+	SciterSelectElementsW,
+	SciterSelectParent,
+	SciterSelectParentW,
+	SciterSetElementHtml,
+	SciterGetElementUID,
+	SciterGetElementByUID,
+	SciterShowPopup,
+	SciterShowPopupAt,
+	SciterHidePopup,
+	SciterGetElementState,
+	SciterSetElementState,
+	SciterCreateElement,
+	SciterCloneElement,
+	SciterInsertElement,
+	SciterDetachElement,
+	SciterDeleteElement,
+	SciterSetTimer,
+	SciterDetachEventHandler,
+	SciterAttachEventHandler,
+	SciterWindowAttachEventHandler,
+	SciterWindowDetachEventHandler,
+	SciterSendEvent,
+	SciterPostEvent,
+	SciterCallBehaviorMethod,
+	SciterRequestElementData,
+	SciterHttpRequest,
+	SciterGetScrollInfo,
+	SciterSetScrollPos,
+	SciterGetElementIntrinsicWidths,
+	SciterGetElementIntrinsicHeight,
+	SciterIsElementVisible,
+	SciterIsElementEnabled,
+	SciterSortElements,
+	SciterSwapElements,
+	SciterTraverseUIEvent,
+	SciterCallScriptingMethod,
+	SciterCallScriptingFunction,
+	SciterEvalElementScript,
+	SciterAttachHwndToElement,
+	SciterControlGetType,
+	SciterGetValue,
+	SciterSetValue,
+	SciterGetExpando,
+	SciterGetObject,
+	SciterGetElementNamespace,
+	SciterGetHighlightedElement,
+	SciterSetHighlightedElement,
+	SciterNodeAddRef,
+	SciterNodeRelease,
+	SciterNodeCastFromElement,
+	SciterNodeCastToElement,
+	SciterNodeFirstChild,
+	SciterNodeLastChild,
+	SciterNodeNextSibling,
+	SciterNodePrevSibling,
+	SciterNodeParent,
+	SciterNodeNthChild,
+	SciterNodeChildrenCount,
+	SciterNodeType,
+	SciterNodeGetText,
+	SciterNodeSetText,
+	SciterNodeInsert,
+	SciterNodeRemove,
+	SciterCreateTextNode,
+	SciterCreateCommentNode,
+	ValueInit,
+	ValueClear,
+	ValueCompare,
+	ValueCopy,
+	ValueIsolate,
+	ValueType,
+	ValueStringData,
+	ValueStringDataSet,
+	ValueIntData,
+	ValueIntDataSet,
+	ValueInt64Data,
+	ValueInt64DataSet,
+	ValueFloatData,
+	ValueFloatDataSet,
+	ValueBinaryData,
+	ValueBinaryDataSet,
+	ValueElementsCount,
+	ValueNthElementValue,
+	ValueNthElementValueSet,
+	ValueNthElementKey,
+	ValueEnumElements,
+	ValueSetValueToKey,
+	ValueGetValueOfKey,
+	ValueToString,
+	ValueFromString,
+	ValueInvoke,
+	ValueNativeFunctorSet,
+	ValueIsNativeFunctor,
+	TIScriptAPI,
+	SciterGetVM,
+	Sciter_v2V,
+	Sciter_V2v,
+	SciterOpenArchive,
+	SciterGetArchiveItem,
+	SciterCloseArchive,
+	SciterFireEvent,
+	SciterGetCallbackParam,
+	SciterPostCallback,
+	GetSciterGraphicsAPI,
+	GetSciterRequestAPI,
+	SciterCreateOnDirectXWindow,
+	SciterRenderOnDirectXWindow,
+	SciterRenderOnDirectXTexture,
+	SciterProcX,
+	SciterAtomValue,
+	SciterAtomNameCB,
+	SciterSetGlobalAsset,
+	SciterGetElementAsset,
+	SciterSetVariable,
+	SciterGetVariable,
+	SciterElementUnwrap,
+	SciterElementWrap,
+	SciterNodeUnwrap,
+	SciterNodeWrap,
+};
@@ -0,0 +1,182 @@
+/*! Crate-level error type.
+
+Every fallible Sciter API returns [`Error`](enum.Error.html) which holds the name of the failed
+Sciter function and its raw result code, so errors of different modules can be propagated with `?`
+into a single `Result` (or into `Box<dyn std::error::Error>`, `anyhow` and so on).
+
+```
+use sciter::Error;
+use sciter::dom::SCDOM_RESULT;
+
+let e = Error::Dom { api: "SciterSetElementText", code: SCDOM_RESULT::INVALID_HANDLE };
+assert_eq!(e.api(), "SciterSetElementText");
+assert_eq!(e.to_string(), "SciterSetElementText failed: INVALID_HANDLE");
+
+let e: Box<dyn std::error::Error> = Box::new(e);
+```
+*/
+
+use capi::scdom::SCDOM_RESULT;
+use capi::scgraphics::GRAPHIN_RESULT;
+use capi::screquest::REQUEST_RESULT;
+use capi::scvalue::VALUE_RESULT;
+use value::{ConversionError, ScriptError};
+
+
+/// A specialized `Result` type for Sciter operations.
+pub type Result<T> = ::std::result::Result<T, Error>;
+
+/// An error returned by the Sciter API.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+	/// A DOM function failed, see [`dom::Result`](../dom/type.Result.html).
+	Dom {
+		/// Name of the failed function.
+		api: &'static str,
+		/// Its result code.
+		code: SCDOM_RESULT,
+	},
+
+	/// A graphics function failed, see [`graphics::Result`](../graphics/type.Result.html).
+	Graphics {
+		/// Name of the failed function.
+		api: &'static str,
+		/// Its result code.
+		code: GRAPHIN_RESULT,
+	},
+
+	/// A request function failed, see [`request::Result`](../request/type.Result.html).
+	Request {
+		/// Name of the failed function.
+		api: &'static str,
+		/// Its result code.
+		code: REQUEST_RESULT,
+	},
+
+	/// A value function failed.
+	Value {
+		/// Name of the failed function.
+		api: &'static str,
+		/// Its result code.
+		code: VALUE_RESULT,
+	},
+
+	/// A host, window or video function which reports no code returned `FALSE`.
+	Failed {
+		/// Name of the failed function.
+		api: &'static str,
+	},
+
+	/// The Sciter library can not be loaded, see [`set_library`](../fn.set_library.html).
+	///
+	/// Holds the message of the loader.
+	Library(String),
+
+	/// An asset does not support the requested interface, see [`video::AssetPtr::try_from`](../video/struct.AssetPtr.html#method.try_from).
+	NoInterface {
+		/// Name of the interface, like `source.video.sciter.com`.
+		name: &'static str,
+	},
+
+	/// An error thrown by script.
+	Script(ScriptError),
+
+	/// A value of unexpected type.
+	Conversion(ConversionError),
+}
+
+impl Error {
+	/// Name of the failed Sciter function.
+	///
+	/// It is empty for errors converted from a bare result code and for script and conversion errors.
+	pub fn api(&self) -> &'static str {
+		match *self {
+			Error::Dom { api, .. }
+			| Error::Graphics { api, .. }
+			| Error::Request { api, .. }
+			| Error::Value { api, .. }
+			| Error::Failed { api } => api,
+			Error::NoInterface { .. } => "get_interface",
+			Error::Library(_) | Error::Script(_) | Error::Conversion(_) => "",
+		}
+	}
+
+	/// Raw result code of the failed function, if any.
+	pub fn code(&self) -> Option<i32> {
+		match *self {
+			Error::Dom { code, .. } => Some(code as i32),
+			Error::Graphics { code, .. } => Some(code as i32),
+			Error::Request { code, .. } => Some(code as i32),
+			Error::Value { code, .. } => Some(code as i32),
+			_ => None,
+		}
+	}
+}
+
+impl ::std::fmt::Display for Error {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+		let api = self.api();
+		let api = if api.is_empty() { "Sciter API" } else { api };
+		match *self {
+			Error::Dom { code, .. } => write!(f, "{} failed: {:?}", api, code),
+			Error::Graphics { code, .. } => write!(f, "{} failed: {:?}", api, code),
+			Error::Request { code, .. } => write!(f, "{} failed: {:?}", api, code),
+			Error::Value { code, .. } => write!(f, "{} failed: {:?}", api, code),
+			Error::Failed { .. } => write!(f, "{} failed", api),
+			Error::Library(ref message) => write!(f, "can't load the Sciter library: {}", message),
+			Error::NoInterface { name } => write!(f, "the asset does not support the {:?} interface", name),
+			Error::Script(_) => f.write_str("script error"),
+			Error::Conversion(_) => f.write_str("conversion error"),
+		}
+	}
+}
+
+impl ::std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match *self {
+			Error::Script(ref e) => Some(e),
+			Error::Conversion(ref e) => Some(e),
+			_ => None,
+		}
+	}
+}
+
+impl From<SCDOM_RESULT> for Error {
+	fn from(code: SCDOM_RESULT) -> Self {
+		Error::Dom { api: "", code }
+	}
+}
+
+impl From<GRAPHIN_RESULT> for Error {
+	fn from(code: GRAPHIN_RESULT) -> Self {
+		Error::Graphics { api: "", code }
+	}
+}
+
+impl From<REQUEST_RESULT> for Error {
+	fn from(code: REQUEST_RESULT) -> Self {
+		Error::Request { api: "", code }
+	}
+}
+
+impl From<VALUE_RESULT> for Error {
+	fn from(code: VALUE_RESULT) -> Self {
+		Error::Value { api: "", code }
+	}
+}
+
+/// A failed call which has thrown nothing becomes its [`api_error`](../value/struct.ScriptError.html#method.api_error).
+impl From<ScriptError> for Error {
+	fn from(e: ScriptError) -> Self {
+		match e.api_error() {
+			Some(error) => error.clone(),
+			None => Error::Script(e),
+		}
+	}
+}
+
+impl From<ConversionError> for Error {
+	fn from(e: ConversionError) -> Self {
+		Error::Conversion(e)
+	}
+}
@@ -192,6 +192,12 @@ fn process_events(me: &mut dyn EventHandler, he: HELEMENT, evtg: UINT, params: L
 					BEHAVIOR_EVENTS::DOCUMENT_CLOSE => {
 						me.document_close(he, nm.heTarget);
 					},
+					BEHAVIOR_EVENTS::POPUP_READY => {
+						me.popup_ready(he, nm.heTarget);
+					},
+					BEHAVIOR_EVENTS::POPUP_DISMISSED => {
+						me.popup_dismissed(he, nm.heTarget);
+					},
 					_ => ()
 				};
 			}
@@ -11,6 +11,7 @@ use capi::sctypes::{BOOL, LPCBYTE, LPVOID, POINT, SIZE, UINT};
 use std::ptr::{null_mut, null};
 use value::{FromValue, Value};
 use dom::Element;
+use error::Error;
 use _GAPI;
 
 pub use capi::scgraphics::{HGFX, GRAPHIN_RESULT};
@@ -31,17 +32,17 @@ pub enum SaveImageEncoding {
 }
 
 macro_rules! ok_or {
-  ($rv:expr, $ok:ident) => {
+  ($rv:expr, $ok:ident, $api:expr) => {
     if $ok == GRAPHIN_RESULT::OK {
       Ok($rv)
     } else {
-      Err($ok)
+      Err(Error::Graphics { api: $api, code: $ok })
     }
   };
 }
 
 /// A specialized `Result` type for graphics operations.
-pub type Result<T> = ::std::result::Result<T, GRAPHIN_RESULT>;
+pub type Result<T> = ::std::result::Result<T, Error>;
 
 /// Color type in the `RGBA` form.
 pub type Color = SC_COLOR;
@@ -143,7 +144,7 @@ impl Text {
 		let (t, tn) = s2wn!(text);
 		let mut h = null_mut();
 		let ok = (_GAPI.textCreateForElement)(&mut h, t.as_ptr(), tn, e.as_ptr(), null());
-		ok_or!(Text(h), ok)
+		ok_or!(Text(h), ok, "textCreateForElement")
 	}
 
 	/// Create a text layout object on top of a host element with the specified `class` attribute.
@@ -152,7 +153,7 @@ impl Text {
 		let (c, _cn) = s2wn!(class);
 		let mut h = null_mut();
 		let ok = (_GAPI.textCreateForElement)(&mut h, t.as_ptr(), tn, e.as_ptr(), c.as_ptr() );
-		ok_or!(Text(h), ok)
+		ok_or!(Text(h), ok, "textCreateForElement")
 	}
 
 	/// Create a text layout object on top of a host element with the specified `style` attribute.
@@ -161,13 +162,13 @@ impl Text {
 		let (s, sn) = s2wn!(styles);
 		let mut h = null_mut();
 		let ok = (_GAPI.textCreateForElementAndStyle)(&mut h, t.as_ptr(), tn, e.as_ptr(), s.as_ptr(), sn);
-		ok_or!(Text(h), ok)
+		ok_or!(Text(h), ok, "textCreateForElementAndStyle")
 	}
 
 	/// Sets the box `width` and `height` of the text object.
 	pub fn set_box(&mut self, size: Size) -> Result<()> {
 		let ok = (_GAPI.textSetBox)(self.0, size.0, size.1);
-		ok_or!((), ok)
+		ok_or!((), ok, "textSetBox")
 	}
 
 	/// Returns metrics of the text layout object.
@@ -179,7 +180,7 @@ impl Text {
 				&mut tm.ascent, &mut tm.descent,
 				&mut tm.lines,
 			);
-		ok_or!(tm, ok)
+		ok_or!(tm, ok, "textGetMetrics")
 	}
 }
 
@@ -236,7 +237,7 @@ impl Image {
   pub fn create((width, height): (u32, u32), with_alpha: bool) -> Result<Image> {
     let mut h = null_mut();
     let ok = (_GAPI.imageCreate)(&mut h, width, height, with_alpha as BOOL);
-    ok_or!(Image(h), ok)
+    ok_or!(Image(h), ok, "imageCreate")
   }
 
   /// Create a new blank image.
@@ -249,7 +250,7 @@ impl Image {
   pub fn with_data((width, height): (u32, u32), with_alpha: bool, pixmap: &[u8]) -> Result<Image> {
     let mut h = null_mut();
     let ok = (_GAPI.imageCreateFromPixmap)(&mut h, width, height, with_alpha as BOOL, pixmap.as_ptr());
-    ok_or!(Image(h), ok)
+    ok_or!(Image(h), ok, "imageCreateFromPixmap")
   }
 
   /// Load image from memory.
@@ -258,7 +259,7 @@ impl Image {
   pub fn load(image_data: &[u8]) -> Result<Image> {
     let mut h = null_mut();
     let ok = (_GAPI.imageLoad)(image_data.as_ptr(), image_data.len() as UINT, &mut h);
-    ok_or!(Image(h), ok)
+    ok_or!(Image(h), ok, "imageLoad")
   }
 
   /// Save content of the image as a byte vector.
@@ -282,7 +283,7 @@ impl Image {
     };
     let mut data = Vec::new();
     let ok = (_GAPI.imageSave)(self.0, on_save, &mut data as *mut _ as LPVOID, enc, u32::from(q));
-    ok_or!(data, ok)
+    ok_or!(data, ok, "imageSave")
   }
 
   /// Render on bitmap image using methods of the [`Graphics`](struct.Graphics.html) object.
@@ -330,7 +331,7 @@ impl Image {
       	let mut gfx = Graphics::from(hgfx);
       	(payload.painter)(&mut gfx, (width as f32, height as f32))
       } else {
-      	Err(GRAPHIN_RESULT::BAD_PARAM)
+      	Err(Error::Graphics { api: "imagePaint", code: GRAPHIN_RESULT::BAD_PARAM })
       };
       payload.result = ok;
     }
@@ -341,7 +342,7 @@ impl Image {
     let param = Box::new(payload);
     let param = Box::into_raw(param);
     let ok = (_GAPI.imagePaint)(self.0, on_paint::<PaintFn>, param as LPVOID);
-    let ok = ok_or!((), ok);
+    let ok = ok_or!((), ok, "imagePaint");
     let param = unsafe { Box::from_raw(param) };
     ok.and(param.result)
   }
@@ -352,19 +353,19 @@ impl Image {
     let mut w = 0;
     let mut h = 0;
     let ok = (_GAPI.imageGetInfo)(self.0, &mut w, &mut h, &mut alpha);
-    ok_or!((w, h), ok)
+    ok_or!((w, h), ok, "imageGetInfo")
   }
 
   /// Clear image by filling it with the black color.
   pub fn clear(&mut self) -> Result<()> {
     let ok = (_GAPI.imageClear)(self.0, Graphics::NO_COLOR);
-    ok_or!((), ok)
+    ok_or!((), ok, "imageClear")
   }
 
   /// Clear image by filling it with the specified `color`.
   pub fn clear_with(&mut self, color: Color) -> Result<()> {
     let ok = (_GAPI.imageClear)(self.0, color);
-    ok_or!((), ok)
+    ok_or!((), ok, "imageClear")
   }
 }
 
@@ -421,7 +422,7 @@ impl Path {
   pub fn create() -> Result<Path> {
     let mut h = null_mut();
     let ok = (_GAPI.pathCreate)(&mut h);
-    ok_or!(Path(h), ok)
+    ok_or!(Path(h), ok, "pathCreate")
   }
 
   /// Create a new empty path.
@@ -433,7 +434,7 @@ impl Path {
   /// Close the current path/figure.
   pub fn close(&mut self) -> Result<()> {
     let ok = (_GAPI.pathClosePath)(self.0);
-    ok_or!((), ok)
+    ok_or!((), ok, "pathClosePath")
   }
 
   /// Move the current drawing path position to `x,y`.
@@ -441,7 +442,7 @@ impl Path {
   /// If `is_relative` is `true` then the specified coordinates are interpreted as deltas from the current path position.
   pub fn move_to(&mut self, point: Pos, is_relative: bool) -> Result<&mut Path> {
     let ok = (_GAPI.pathMoveTo)(self.0, point.0, point.1, is_relative as BOOL);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "pathMoveTo")
   }
 
   /// Draw a line and move the current drawing path position to `x,y`.
@@ -449,7 +450,7 @@ impl Path {
   /// If `is_relative` is `true` then the specified coordinates are interpreted as deltas from the current path position.
   pub fn line_to(&mut self, point: Pos, is_relative: bool) -> Result<&mut Path> {
     let ok = (_GAPI.pathLineTo)(self.0, point.0, point.1, is_relative as BOOL);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "pathLineTo")
   }
 
   /// Draw an arc.
@@ -465,7 +466,7 @@ impl Path {
       is_clockwise as BOOL,
       is_relative as BOOL,
     );
-    ok_or!(self, ok)
+    ok_or!(self, ok, "pathArcTo")
   }
 
   /// Draw a quadratic Bézier curve.
@@ -473,7 +474,7 @@ impl Path {
   /// If `is_relative` is `true` then the specified coordinates are interpreted as deltas from the current path position.
   pub fn quadratic_curve_to(&mut self, control: Pos, end: Pos, is_relative: bool) -> Result<&mut Path> {
     let ok = (_GAPI.pathQuadraticCurveTo)(self.0, control.0, control.1, end.0, end.1, is_relative as BOOL);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "pathQuadraticCurveTo")
   }
 
   /// Draw a cubic Bézier curve.
@@ -490,7 +491,7 @@ impl Path {
       end.1,
       is_relative as BOOL,
     );
-    ok_or!(self, ok)
+    ok_or!(self, ok, "pathBezierCurveTo")
   }
 }
 
@@ -607,19 +608,19 @@ impl Graphics {
   /// Manually save the current graphics attributes on top of the internal state stack.
   fn push_state(&mut self) -> Result<&mut Self> {
     let ok = (_GAPI.gStateSave)(self.0);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gStateSave")
   }
 
   /// Manually restore graphics attributes from top of the internal state stack.
   fn pop_state(&mut self) -> Result<&mut Self> {
     let ok = (_GAPI.gStateRestore)(self.0);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gStateRestore")
 	}
 
 	/// Flush all pending graphic operations.
 	pub fn flush(&mut self) -> Result<&mut Self> {
 		let ok = (_GAPI.gFlush)(self.0);
-		ok_or!(self, ok)
+		ok_or!(self, ok, "gFlush")
 	}
 }
 
@@ -630,20 +631,20 @@ impl Graphics {
   /// Draw a line from the `start` to the `end`.
   pub fn line(&mut self, start: Pos, end: Pos) -> Result<&mut Self> {
     let ok = (_GAPI.gLine)(self.0, start.0, start.1, end.0, end.1);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gLine")
   }
 
   /// Draw a rectangle.
   pub fn rectangle(&mut self, left_top: Pos, right_bottom: Pos) -> Result<&mut Self> {
     let ok = (_GAPI.gRectangle)(self.0, left_top.0, left_top.1, right_bottom.0, right_bottom.1);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gRectangle")
   }
 
   /// Draw a rounded rectangle with the same corners.
   pub fn round_rect(&mut self, left_top: Pos, right_bottom: Pos, radius: Dim) -> Result<&mut Self> {
     let rad: [Dim; 8] = [radius; 8usize];
     let ok = (_GAPI.gRoundedRectangle)(self.0, left_top.0, left_top.1, right_bottom.0, right_bottom.1, rad.as_ptr());
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gRoundedRectangle")
   }
 
   /// Draw a rounded rectangle with different corners.
@@ -651,31 +652,31 @@ impl Graphics {
     let r = radius;
     let rad: [Dim; 8] = [r.0, r.0, r.1, r.1, r.2, r.2, r.3, r.3];
     let ok = (_GAPI.gRoundedRectangle)(self.0, left_top.0, left_top.1, right_bottom.0, right_bottom.1, rad.as_ptr());
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gRoundedRectangle")
   }
 
   /// Draw an ellipse.
   pub fn ellipse(&mut self, xy: Pos, radii: Pos) -> Result<&mut Self> {
     let ok = (_GAPI.gEllipse)(self.0, xy.0, xy.1, radii.0, radii.1);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gEllipse")
   }
 
   /// Draw a circle.
   pub fn circle(&mut self, xy: Pos, radius: Dim) -> Result<&mut Self> {
     let ok = (_GAPI.gEllipse)(self.0, xy.0, xy.1, radius, radius);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gEllipse")
   }
 
   /// Draw a closed arc.
   pub fn arc(&mut self, xy: Pos, rxy: Pos, start: Angle, sweep: Angle) -> Result<&mut Self> {
     let ok = (_GAPI.gArc)(self.0, xy.0, xy.1, rxy.0, rxy.1, start, sweep);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gArc")
   }
 
   /// Draw a star.
   pub fn star(&mut self, xy: Pos, r1: Dim, r2: Dim, start: Angle, rays: usize) -> Result<&mut Self> {
     let ok = (_GAPI.gStar)(self.0, xy.0, xy.1, r1, r2, start, rays as UINT);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gStar")
   }
 
   /// Draw a closed polygon.
@@ -686,7 +687,7 @@ impl Graphics {
     let _ = ::std::mem::transmute::<FloatArray, PosArray>;
 
     let ok = (_GAPI.gPolygon)(self.0, points.as_ptr() as *const SC_POS, points.len() as UINT);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gPolygon")
   }
 
   /// Draw a polyline.
@@ -697,7 +698,7 @@ impl Graphics {
     let _ = ::std::mem::transmute::<FloatArray, PosArray>;
 
     let ok = (_GAPI.gPolyline)(self.0, points.as_ptr() as *const SC_POS, points.len() as UINT);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gPolyline")
   }
 }
 
@@ -708,7 +709,7 @@ impl Graphics {
   /// Set the color for solid fills for subsequent drawings.
   pub fn fill_color(&mut self, color: Color) -> Result<&mut Self> {
     let ok = (_GAPI.gFillColor)(self.0, color);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gFillColor")
   }
 
   /// Set the even/odd rule of solid fills for subsequent drawings.
@@ -716,7 +717,7 @@ impl Graphics {
   /// `false` means "fill non zero".
   pub fn fill_mode(&mut self, is_even: bool) -> Result<&mut Self> {
     let ok = (_GAPI.gFillMode)(self.0, is_even as BOOL);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gFillMode")
   }
 
   /// Disables fills for subsequent drawing operations.
@@ -727,13 +728,13 @@ impl Graphics {
   /// Set the line color for subsequent drawings.
   pub fn line_color(&mut self, color: Color) -> Result<&mut Self> {
     let ok = (_GAPI.gLineColor)(self.0, color);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gLineColor")
   }
 
   /// Set the line width for subsequent drawings.
   pub fn line_width(&mut self, width: Dim) -> Result<&mut Self> {
     let ok = (_GAPI.gLineWidth)(self.0, width);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gLineWidth")
   }
 
   /// Set the line cap mode (stroke dash ending style) for subsequent drawings.
@@ -743,7 +744,7 @@ impl Graphics {
   /// By default this property is set to `BUTT`.
   pub fn line_cap(&mut self, style: LINE_CAP) -> Result<&mut Self> {
     let ok = (_GAPI.gLineCap)(self.0, style);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gLineCap")
   }
 
   /// Set the line join mode for subsequent drawings.
@@ -754,7 +755,7 @@ impl Graphics {
   /// are exactly at the same position, are skipped).
   pub fn line_join(&mut self, style: LINE_JOIN) -> Result<&mut Self> {
     let ok = (_GAPI.gLineJoin)(self.0, style);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gLineJoin")
   }
 
   /// Disable outline drawing.
@@ -780,7 +781,7 @@ impl Graphics {
       colors.as_ptr() as *const SC_COLOR_STOP,
       colors.len() as UINT,
     );
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gLineGradientLinear")
   }
 
   /// Setup parameters of linear gradient fills.
@@ -801,7 +802,7 @@ impl Graphics {
       colors.as_ptr() as *const SC_COLOR_STOP,
       colors.len() as UINT,
     );
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gFillGradientLinear")
   }
 
   /// Setup parameters of a radial gradient of lines.
@@ -822,7 +823,7 @@ impl Graphics {
       colors.as_ptr() as *const SC_COLOR_STOP,
       colors.len() as UINT,
     );
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gLineGradientRadial")
   }
 
   /// Setup parameters of radial gradient of fills.
@@ -843,7 +844,7 @@ impl Graphics {
       colors.as_ptr() as *const SC_COLOR_STOP,
       colors.len() as UINT,
     );
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gFillGradientRadial")
   }
 }
 
@@ -852,19 +853,19 @@ impl Graphics {
   /// Rotate coordinate system on `radians` angle.
   pub fn rotate(&mut self, radians: Angle) -> Result<&mut Self> {
     let ok = (_GAPI.gRotate)(self.0, radians, None, None);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gRotate")
   }
 
   /// Rotate coordinate system on `radians` angle around the `center`.
   pub fn rotate_around(&mut self, radians: Angle, center: Pos) -> Result<&mut Self> {
     let ok = (_GAPI.gRotate)(self.0, radians, Some(&center.0), Some(&center.1));
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gRotate")
   }
 
   /// Move origin of coordinate system to the `(to_x, to_y)` point.
   pub fn translate(&mut self, to_xy: Pos) -> Result<&mut Self> {
     let ok = (_GAPI.gTranslate)(self.0, to_xy.0, to_xy.1);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gTranslate")
   }
 
   /// Scale coordinate system.
@@ -875,13 +876,13 @@ impl Graphics {
   /// Values smaller than `1.0` reduce the unit size and values larger than `1.0` increase the unit size.
   pub fn scale(&mut self, sc_xy: Pos) -> Result<&mut Self> {
     let ok = (_GAPI.gScale)(self.0, sc_xy.0, sc_xy.1);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gScale")
   }
 
   /// Setup a skewing (shearing) transformation.
   pub fn skew(&mut self, sh_xy: Pos) -> Result<&mut Self> {
     let ok = (_GAPI.gSkew)(self.0, sh_xy.0, sh_xy.1);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gSkew")
   }
 
   /// Multiply the current transformation with the matrix described by the arguments.
@@ -905,7 +906,7 @@ impl Graphics {
     // m11, m12, m21, m22, dx, dy
     // scx, shx, shy, scy, dx, dy
     let ok = (_GAPI.gTransform)(self.0, scale_by.0, skew_by.0, skew_by.1, scale_by.0, move_to.0, move_to.1);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gTransform")
   }
 
   /// Multiply the current transformation with the matrix described by the arguments.
@@ -939,7 +940,7 @@ impl Graphics {
   /// to the screen coordinate system.
   pub fn world_to_screen(&self, mut xy: Pos) -> Result<Pos> {
     let ok = (_GAPI.gWorldToScreen)(self.0, &mut xy.0, &mut xy.1);
-    ok_or!(xy, ok)
+    ok_or!(xy, ok, "gWorldToScreen")
   }
 
   /// Translate coordinates.
@@ -949,7 +950,7 @@ impl Graphics {
   pub fn world_to_screen1(&self, mut length: Dim) -> Result<Dim> {
     let mut dummy = 0.0;
     let ok = (_GAPI.gWorldToScreen)(self.0, &mut length, &mut dummy);
-    ok_or!(length, ok)
+    ok_or!(length, ok, "gWorldToScreen")
   }
 
   /// Translate coordinates.
@@ -957,7 +958,7 @@ impl Graphics {
   /// Translates coordinates from screen coordinate system to the one defined by `rotate()`, `scale()`, `translate()` and/or `skew()`.
   pub fn screen_to_world(&self, mut xy: Pos) -> Result<Pos> {
     let ok = (_GAPI.gScreenToWorld)(self.0, &mut xy.0, &mut xy.1);
-    ok_or!(xy, ok)
+    ok_or!(xy, ok, "gScreenToWorld")
   }
 
   /// Translate coordinates.
@@ -966,7 +967,7 @@ impl Graphics {
   pub fn screen_to_world1(&self, mut length: Dim) -> Result<Dim> {
     let mut dummy = 0.0;
     let ok = (_GAPI.gScreenToWorld)(self.0, &mut length, &mut dummy);
-    ok_or!(length, ok)
+    ok_or!(length, ok, "gScreenToWorld")
   }
 }
 
@@ -982,19 +983,19 @@ impl Graphics {
       right_bottom.1,
       opacity.unwrap_or(1.0),
     );
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gPushClipBox")
   }
 
   /// Push a clip layer defined by the specified `path` bounds.
   pub fn push_clip_path(&mut self, path: &Path, opacity: Option<f32>) -> Result<&mut Self> {
     let ok = (_GAPI.gPushClipPath)(self.0, path.0, opacity.unwrap_or(1.0));
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gPushClipPath")
   }
 
   /// Pop a clip layer set by previous `push_clip_box()` or `push_clip_path()` calls.
   pub fn pop_clip(&mut self) -> Result<&mut Self> {
     let ok = (_GAPI.gPopClip)(self.0);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gPopClip")
   }
 }
 
@@ -1008,13 +1009,13 @@ impl Graphics {
 	///
 	pub fn draw_text(&mut self, text: &Text, pos: Pos, point_of: u32) -> Result<&mut Self> {
 		let ok = (_GAPI.gDrawText)(self.0, text.0, pos.0, pos.1, point_of);
-		ok_or!(self, ok)
+		ok_or!(self, ok, "gDrawText")
 	}
 
   /// Draw the path object using current fill and stroke brushes.
   pub fn draw_path(&mut self, path: &Path, mode: DRAW_PATH) -> Result<&mut Self> {
     let ok = (_GAPI.gDrawPath)(self.0, path.0, mode);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gDrawPath")
   }
 
   /// Draw the whole image onto the graphics surface.
@@ -1024,7 +1025,7 @@ impl Graphics {
   /// Performance: expensive.
   pub fn draw_image(&mut self, image: &Image, pos: Pos) -> Result<&mut Self> {
     let ok = (_GAPI.gDrawImage)(self.0, image.0, pos.0, pos.1, None, None, None, None, None, None, None);
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gDrawImage")
   }
 
   /// Draw a part of the image onto the graphics surface.
@@ -1050,7 +1051,7 @@ impl Graphics {
       Some(&ih),
       None,
     );
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gDrawImage")
   }
 
   /// Blend the image with the graphics surface.
@@ -1072,7 +1073,7 @@ impl Graphics {
       None,
       Some(&opacity),
     );
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gDrawImage")
   }
 
   /// Blend a part of the image with the graphics surface.
@@ -1098,6 +1099,6 @@ impl Graphics {
       Some(&ih),
       Some(&opacity),
     );
-    ok_or!(self, ok)
+    ok_or!(self, ok, "gDrawImage")
   }
 }
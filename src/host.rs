@@ -7,39 +7,35 @@ use capi::screquest::HREQUEST;
 use capi::schandler::NativeHandler;
 use dom::{self, event::EventHandler};
 use eventhandler::*;
-use value::{Value};
+use value::{FromValue, IntoArgs, Value, ScriptError};
+use error::Error;
 
 pub use capi::scdef::{LOAD_RESULT, OUTPUT_SUBSYTEMS, OUTPUT_SEVERITY};
-pub use capi::scdef::{SCN_LOAD_DATA, SCN_DATA_LOADED, SCN_ATTACH_BEHAVIOR, SCN_INVALIDATE_RECT};
+pub use capi::scdef::{SCN_LOAD_DATA, SCN_DATA_LOADED, SCN_ATTACH_BEHAVIOR, SCN_INVALIDATE_RECT, SCN_POSTED_NOTIFICATION};
 
 
 /// A specialized `Result` type for Sciter host operations.
-pub type Result<T> = ::std::result::Result<T, ()>;
+pub type Result<T> = ::std::result::Result<T, Error>;
 
 macro_rules! ok_or {
-	($ok:ident) => {
+	($ok:ident, $api:expr) => {
 		if $ok != 0 {
 			Ok(())
 		} else {
-			Err(())
-		}
-	};
-
-	($ok:ident, $rv:expr) => {
-		if $ok != 0 {
-			Ok($rv)
-		} else {
-			Err(())
+			Err(Error::Failed { api: $api })
 		}
 	};
+}
 
-	($ok:ident, $rv:expr, $err:expr) => {
-		if $ok != 0 {
-			Ok($rv)
-		} else {
-			Err($err)
-		}
-	};
+/// The script result or the thrown error.
+fn script_result(api: &'static str, ok: BOOL, rv: Value) -> ::std::result::Result<Value, ScriptError> {
+	if ok != 0 {
+		ScriptError::check(rv)
+	} else if rv.is_undefined() {
+		Err(ScriptError::failed(Error::Failed { api }))
+	} else {
+		Err(ScriptError::from_value(rv))
+	}
 }
 
 
@@ -91,6 +87,12 @@ pub trait HostHandler {
 	/// This notification is sent when the engine needs some area to be redrawn.
 	fn on_invalidate(&mut self, pnm: &SCN_INVALIDATE_RECT) {}
 
+	/// This notification is sent on the UI thread for the parameters posted via `SciterPostCallback`.
+	///
+	/// The returned value is passed back to `SciterPostCallback` if it waits for the result.
+	/// Notifications of [`value::Promise`](../value/struct.Promise.html) are handled internally and do not come here.
+	fn on_posted_notification(&mut self, wparam: UINT_PTR, lparam: UINT_PTR) -> UINT_PTR { 0 }
+
 	/// This output function will be used for reporting problems found while loading html and css documents.
 	fn on_debug_output(&mut self, subsystem: OUTPUT_SUBSYTEMS, severity: OUTPUT_SEVERITY, message: &str) {
 		if !message.is_empty() {
@@ -309,26 +311,42 @@ impl Host {
 
 	/// Evaluate the given script in context of the current document.
 	///
-	/// This function returns `Result<Value,Value>` with script function result value or with Sciter script error.
-	pub fn eval_script(&self, script: &str) -> ::std::result::Result<Value, Value> {
+	/// This function returns the script result value or the [`ScriptError`](../value/struct.ScriptError.html) thrown by script.
+	pub fn eval_script(&self, script: &str) -> ::std::result::Result<Value, ScriptError> {
 		let (s,n) = s2wn!(script);
 		let mut rv = Value::new();
 		let ok = (_API.SciterEval)(self.hwnd, s.as_ptr(), n, rv.as_ptr());
-		ok_or!(ok, rv, rv)
+		script_result("SciterEval", ok, rv)
 	}
 
 	/// Call a script function defined in the global namespace.
 	///
-	/// This function returns `Result<Value,Value>` with script function result value or with Sciter script error.
+	/// This function returns the script function result value or the [`ScriptError`](../value/struct.ScriptError.html) thrown by script.
 	///
 	/// You can use the [`&make_args!(args...)`](../macro.make_args.html) macro which helps you
 	/// to construct script arguments from Rust types.
-	pub fn call_function(&self, name: &str, args: &[Value]) -> ::std::result::Result<Value, Value> {
+	pub fn call_function(&self, name: &str, args: &[Value]) -> ::std::result::Result<Value, ScriptError> {
 		let mut rv = Value::new();
 		let s = s2u!(name);
 		let argv = Value::pack_args(args);
 		let ok = (_API.SciterCall)(self.hwnd, s.as_ptr(), argv.len() as UINT, argv.as_ptr(), rv.as_ptr());
-		ok_or!(ok, rv, rv)
+		script_result("SciterCall", ok, rv)
+	}
+
+	/// Call a script function defined in the global namespace with typed arguments and result.
+	///
+	/// The arguments are a tuple of `Into<Value>` types (see [`IntoArgs`](../value/trait.IntoArgs.html))
+	/// and the result is converted via [`FromValue`](../value/trait.FromValue.html);
+	/// a script error or a result of unexpected type is reported as [`Error`](../error/enum.Error.html).
+	///
+	/// ```rust,no_run
+	/// # let host = sciter::Host::attach(0 as sciter::types::HWINDOW);
+	/// let sum: i32 = host.call("math.sum", (1, 2)).unwrap();
+	/// let items = host.call::<(i32, &str), Vec<String>>("app.items", (10, "name")).unwrap();
+	/// ```
+	pub fn call<A: IntoArgs, R: FromValue>(&self, name: &str, args: A) -> ::std::result::Result<R, Error> {
+		let rv = self.call_function(name, &args.into_args())?;
+		Ok(R::try_from_value(&rv)?)
 	}
 
 	/// Set home url for Sciter resources.
@@ -342,7 +360,7 @@ impl Host {
 	pub fn set_home_url(&self, url: &str) -> Result<()> {
 		let s = s2w!(url);
 		let ok = (_API.SciterSetHomeURL)(self.hwnd, s.as_ptr());
-		ok_or!(ok)
+		ok_or!(ok, "SciterSetHomeURL")
 	}
 
 	/// Set media type of this Sciter instance.
@@ -356,7 +374,7 @@ impl Host {
 	pub fn set_media_type(&self, media_type: &str) -> Result<()> {
 		let s = s2w!(media_type);
 		let ok = (_API.SciterSetMediaType)(self.hwnd, s.as_ptr());
-		ok_or!(ok)
+		ok_or!(ok, "SciterSetMediaType")
 	}
 
 	/// Set media variables (dictionary) for this Sciter instance.
@@ -377,7 +395,7 @@ impl Host {
 	/// ```
 	pub fn set_media_vars(&self, media: &Value) -> Result<()> {
 		let ok = (_API.SciterSetMediaVars)(self.hwnd, media.as_cptr());
-		ok_or!(ok)
+		ok_or!(ok, "SciterSetMediaVars")
 	}
 
 	/// Set or append the [master](https://sciter.com/css-extensions-in-h-smile-engine-part-i-style-sets/)
@@ -386,12 +404,13 @@ impl Host {
 		let s = s2u!(css);
 		let b = s.as_bytes();
 		let n = b.len() as UINT;
-		let ok = if append {
-			(_API.SciterAppendMasterCSS)(b.as_ptr(), n)
+		if append {
+			let ok = (_API.SciterAppendMasterCSS)(b.as_ptr(), n);
+			ok_or!(ok, "SciterAppendMasterCSS")
 		} else {
-			(_API.SciterSetMasterCSS)(b.as_ptr(), n)
-		};
-		ok_or!(ok)
+			let ok = (_API.SciterSetMasterCSS)(b.as_ptr(), n);
+			ok_or!(ok, "SciterSetMasterCSS")
+		}
 	}
 
 	/// Set (reset) style sheet of the **current** document.
@@ -404,7 +423,7 @@ impl Host {
 		let b = s.as_bytes();
 		let n = b.len() as UINT;
 		let ok = (_API.SciterSetCSS)(self.hwnd, b.as_ptr(), n, url.as_ptr(), media.as_ptr());
-		ok_or!(ok)
+		ok_or!(ok, "SciterSetCSS")
 	}
 
 }
@@ -490,7 +509,16 @@ extern "system" fn _on_handle_notification<T: HostHandler>(pnm: *mut ::capi::scd
 			let scnm = pnm as *const SCN_INVALIDATE_RECT;
 			me.on_invalidate(unsafe { &*scnm });
 			0
-		}
+		},
+
+		SCITER_NOTIFICATION::SC_POSTED_NOTIFICATION => {
+			let scnm = pnm as *mut SCN_POSTED_NOTIFICATION;
+			let scnm = unsafe { &mut *scnm };
+			if !::value::settle_posted(scnm.wparam, scnm.lparam) {
+				scnm.lreturn = me.on_posted_notification(scnm.wparam, scnm.lparam);
+			}
+			0
+		},
 
 		_ => 0,
 	};
@@ -553,7 +581,7 @@ impl Archive {
     if !p.is_null() {
       Ok(Archive(p))
     } else {
-      Err(())
+      Err(Error::Failed { api: "SciterOpenArchive" })
     }
   }
 
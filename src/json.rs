@@ -0,0 +1,464 @@
+//! JSON representation of Sciter values.
+//!
+//! Plain data maps to JSON directly, Sciter-specific types are stored as single-key objects
+//! with a reserved `$`-prefixed key, see [`Value::to_json_string`](../value/struct.Value.html#method.to_json_string).
+
+use std::fmt::Write;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use value::{Value, VALUE_TYPE};
+
+
+/// Payload of a Sciter-specific value in JSON.
+enum Payload {
+	Int(i64),
+	Float(f64),
+	Str(String),
+	Pair(i32, i32),
+}
+
+/// Reserved key and payload of a Sciter-specific value, `None` for plain data.
+fn tagged(value: &Value) -> Option<(&'static str, Payload)> {
+	let tagged = if value.is_symbol() {
+		("$symbol", Payload::Str(value.as_string()?))
+	} else if value.is_error_string() {
+		("$error", Payload::Str(value.as_string()?))
+	} else if value.is_bytes() {
+		("$bytes", Payload::Str(base64_encode(value.as_bytes()?)))
+	} else if value.is_color() {
+		("$color", Payload::Int(value.to_color()? as i64))
+	} else if value.is_date() {
+		("$date", Payload::Str(format_date(value.to_date()?)))
+	} else if value.is_currency() {
+		("$currency", Payload::Str(value.to_currency()?.to_string()))
+	} else if value.is_length() {
+		("$length", Payload::Str(value.to_length()?.to_string()))
+	} else if value.is_range() {
+		let range = value.to_range()?;
+		("$range", Payload::Pair(range.start, range.end))
+	} else if value.is_duration() {
+		("$duration", Payload::Float(value.to_duration()?))
+	} else if value.is_angle() {
+		("$angle", Payload::Float(value.to_angle()?))
+	} else {
+		return None;
+	};
+	Some(tagged)
+}
+
+/// Name of the map key; non-string keys are stored as their JSON text.
+fn key_name(key: &Value) -> String {
+	key.as_string().unwrap_or_else(|| key.to_string())
+}
+
+
+/// Whether a plain map looks like a Sciter-specific value and is escaped as `{"$map": {...}}`.
+fn is_reserved(items: &[(Value, Value)]) -> bool {
+	items.len() == 1 && key_name(&items[0].0).starts_with('$')
+}
+
+
+/// Serialize the value as JSON text.
+pub(crate) fn to_string(value: &Value, pretty: bool) -> String {
+	let mut out = String::new();
+	write_value(&mut out, value, if pretty { Some(0) } else { None });
+	out
+}
+
+fn write_value(out: &mut String, value: &Value, indent: Option<usize>) {
+	if let Some((tag, payload)) = tagged(value) {
+		return write_object(out, indent, 1, |out, _, indent| {
+			write_str(out, tag);
+			out.push_str(if indent.is_some() { ": " } else { ":" });
+			match payload {
+				Payload::Int(v) => { let _ = write!(out, "{}", v); },
+				Payload::Float(v) => write_float(out, v),
+				Payload::Str(ref s) => write_str(out, s),
+				Payload::Pair(a, b) => write_seq(out, indent, "[", "]", 2, |out, i, _| {
+					let _ = write!(out, "{}", if i == 0 { a } else { b });
+				}),
+			}
+		});
+	}
+
+	match value.get_type() {
+		VALUE_TYPE::T_BOOL => out.push_str(if value.to_bool() == Some(true) { "true" } else { "false" }),
+		VALUE_TYPE::T_INT => { let _ = write!(out, "{}", value.to_int().unwrap_or_default()); },
+		VALUE_TYPE::T_FLOAT => write_float(out, value.to_float().unwrap_or_default()),
+		VALUE_TYPE::T_STRING => write_str(out, &value.as_string().unwrap_or_default()),
+		VALUE_TYPE::T_ARRAY => {
+			let items: Vec<Value> = value.values().collect();
+			write_seq(out, indent, "[", "]", items.len(), |out, i, indent| {
+				write_value(out, &items[i], indent);
+			});
+		},
+		VALUE_TYPE::T_MAP => {
+			let items = value.items();
+			if is_reserved(&items) {
+				write_object(out, indent, 1, |out, _, indent| {
+					write_str(out, "$map");
+					out.push_str(if indent.is_some() { ": " } else { ":" });
+					write_map(out, &items, indent);
+				});
+			} else {
+				write_map(out, &items, indent);
+			}
+		},
+		VALUE_TYPE::T_OBJECT => {
+			// script arrays and objects
+			let mut copy = value.clone();
+			copy.isolate();
+			if copy.is_array() || copy.is_map() {
+				write_value(out, &copy, indent);
+			} else {
+				out.push_str("null");
+			}
+		},
+		// `undefined`, `null` and values without JSON representation
+		_ => out.push_str("null"),
+	}
+}
+
+fn write_map(out: &mut String, items: &[(Value, Value)], indent: Option<usize>) {
+	write_object(out, indent, items.len(), |out, i, indent| {
+		write_str(out, &key_name(&items[i].0));
+		out.push_str(if indent.is_some() { ": " } else { ":" });
+		write_value(out, &items[i].1, indent);
+	});
+}
+
+fn write_object<F>(out: &mut String, indent: Option<usize>, len: usize, item: F)
+	where F: FnMut(&mut String, usize, Option<usize>)
+{
+	write_seq(out, indent, "{", "}", len, item)
+}
+
+fn write_seq<F>(out: &mut String, indent: Option<usize>, open: &str, close: &str, len: usize, mut item: F)
+	where F: FnMut(&mut String, usize, Option<usize>)
+{
+	out.push_str(open);
+	if len == 0 {
+		out.push_str(close);
+		return;
+	}
+	let inner = indent.map(|n| n + 1);
+	for i in 0..len {
+		if i > 0 {
+			out.push(',');
+		}
+		if let Some(n) = inner {
+			out.push('\n');
+			out.push_str(&"  ".repeat(n));
+		}
+		item(out, i, inner);
+	}
+	if let Some(n) = indent {
+		out.push('\n');
+		out.push_str(&"  ".repeat(n));
+	}
+	out.push_str(close);
+}
+
+fn write_float(out: &mut String, v: f64) {
+	let _ = if !v.is_finite() {
+		write!(out, "null")
+	} else if v == v.trunc() && v.abs() < 1e16 {
+		// keep it a float: `1.0`
+		write!(out, "{:.1}", v)
+	} else if v != 0.0 && (v.abs() >= 1e16 || v.abs() < 1e-5) {
+		write!(out, "{:e}", v)
+	} else {
+		write!(out, "{}", v)
+	};
+}
+
+fn write_str(out: &mut String, s: &str) {
+	out.push('"');
+	for c in s.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			'\u{08}' => out.push_str("\\b"),
+			'\u{0C}' => out.push_str("\\f"),
+			c if (c as u32) < 0x20 => { let _ = write!(out, "\\u{:04x}", c as u32); },
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+}
+
+
+// `2017-07-14T02:40:00.1234567Z`
+fn format_date(t: SystemTime) -> String {
+	let nanos = match t.duration_since(UNIX_EPOCH) {
+		Ok(d) => d.as_nanos() as i128,
+		Err(e) => -(e.duration().as_nanos() as i128),
+	};
+	let secs = nanos.div_euclid(1_000_000_000) as i64;
+	let fraction = nanos.rem_euclid(1_000_000_000) / 100;
+	let (days, secs) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+	let (year, month, day) = civil_from_days(days);
+	let mut s = format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}", year, month, day, secs / 3600, secs / 60 % 60, secs % 60);
+	if fraction != 0 {
+		let _ = write!(s, ".{:07}", fraction);
+	}
+	s.push('Z');
+	s
+}
+
+#[cfg_attr(not(feature = "json"), allow(dead_code))]
+fn parse_date(s: &str) -> Option<SystemTime> {
+	let b = s.as_bytes();
+	if b.len() < 20 || b[4] != b'-' || b[7] != b'-' || b[10] != b'T' || b[13] != b':' || b[16] != b':' || b[b.len() - 1] != b'Z' {
+		return None;
+	}
+	let num = |from: usize, to: usize| -> Option<i64> {
+		let part = s.get(from..to)?;
+		if part.bytes().all(|c| c.is_ascii_digit()) { part.parse().ok() } else { None }
+	};
+	let (year, month, day) = (num(0, 4)?, num(5, 7)?, num(8, 10)?);
+	let (hour, minute, second) = (num(11, 13)?, num(14, 16)?, num(17, 19)?);
+	if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 59 {
+		return None;
+	}
+	let mut nanos = 0_i128;
+	if b.len() > 20 {
+		let digits = s.get(20..b.len() - 1)?;
+		if b[19] != b'.' || digits.is_empty() || digits.len() > 9 {
+			return None;
+		}
+		nanos = num(20, b.len() - 1)? as i128 * 10_i128.pow(9 - digits.len() as u32);
+	}
+	let secs = days_from_civil(year, month as u32, day as u32) * 86_400 + hour * 3600 + minute * 60 + second;
+	let total = secs as i128 * 1_000_000_000 + nanos;
+	let d = Duration::new((total.unsigned_abs() / 1_000_000_000) as u64, (total.unsigned_abs() % 1_000_000_000) as u32);
+	// not every platform can represent dates that far from the epoch
+	if total < 0 { UNIX_EPOCH.checked_sub(d) } else { UNIX_EPOCH.checked_add(d) }
+}
+
+// Days since 1970-01-01 to the proleptic Gregorian date,
+// see http://howardhinnant.github.io/date_algorithms.html.
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+	let z = z + 719_468;
+	let era = z.div_euclid(146_097);
+	let doe = z.rem_euclid(146_097);
+	let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+	let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+	(year, month, day)
+}
+
+#[cfg_attr(not(feature = "json"), allow(dead_code))]
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year.div_euclid(400);
+	let yoe = year.rem_euclid(400);
+	let mp = if month > 2 { month - 3 } else { month + 9 } as i64;
+	let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+	let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+	era * 146_097 + doe - 719_468
+}
+
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(bytes: &[u8]) -> String {
+	let mut out = String::with_capacity((bytes.len() / 3 + 1) * 4);
+	for chunk in bytes.chunks(3) {
+		let n = chunk.iter().enumerate().fold(0_u32, |n, (i, &b)| n | (b as u32) << (16 - 8 * i));
+		for i in 0..4 {
+			if i <= chunk.len() {
+				out.push(BASE64[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+			} else {
+				out.push('=');
+			}
+		}
+	}
+	out
+}
+
+#[cfg_attr(not(feature = "json"), allow(dead_code))]
+fn base64_decode(s: &str) -> Option<Vec<u8>> {
+	let s = s.trim_end_matches('=');
+	let mut out = Vec::with_capacity(s.len() * 3 / 4);
+	let (mut n, mut bits) = (0_u32, 0);
+	for c in s.bytes() {
+		let v = BASE64.iter().position(|&b| b == c)? as u32;
+		n = n << 6 | v;
+		bits += 6;
+		if bits >= 8 {
+			bits -= 8;
+			out.push((n >> bits) as u8);
+			n &= (1 << bits) - 1;
+		}
+	}
+	Some(out)
+}
+
+
+#[cfg(feature = "json")]
+mod interop {
+	use std::convert::TryFrom;
+
+	use serde_json::{self, Map, Number};
+
+	use super::*;
+	use value::{ConversionError, Currency, Range};
+
+	/// Converts JSON to Sciter value; reserved single-key objects are restored to Sciter-specific types
+	/// and escaped `{"$map": {...}}` ones to plain maps,
+	/// see [`Value::to_json_string`](struct.Value.html#method.to_json_string).
+	///
+	/// Integers out of the `i32` range are stored as floats.
+	impl<'a> From<&'a serde_json::Value> for Value {
+		fn from(json: &'a serde_json::Value) -> Self {
+			match *json {
+				serde_json::Value::Null => Value::null(),
+				serde_json::Value::Bool(v) => Value::from(v),
+				serde_json::Value::Number(ref n) => match n.as_i64() {
+					Some(v) if v >= i32::MIN as i64 && v <= i32::MAX as i64 => Value::from(v as i32),
+					_ => Value::from(n.as_f64().unwrap_or_default()),
+				},
+				serde_json::Value::String(ref s) => Value::from(s.as_str()),
+				serde_json::Value::Array(ref items) => items.iter().map(Value::from).collect(),
+				serde_json::Value::Object(ref map) => {
+					if map.len() == 1 {
+						let (tag, payload) = map.iter().next().unwrap();
+						if let Some(v) = untagged(tag, payload) {
+							return v;
+						}
+					}
+					plain(map)
+				},
+			}
+		}
+	}
+
+	impl From<serde_json::Value> for Value {
+		fn from(json: serde_json::Value) -> Self {
+			Value::from(&json)
+		}
+	}
+
+	/// Converts Sciter value to JSON, see [`Value::to_json_string`](struct.Value.html#method.to_json_string)
+	/// for the mapping of Sciter-specific types.
+	///
+	/// Fails on values that have no JSON representation, like functions, DOM elements or assets.
+	impl<'a> TryFrom<&'a Value> for serde_json::Value {
+		type Error = ConversionError;
+
+		fn try_from(value: &'a Value) -> Result<Self, ConversionError> {
+			if let Some((tag, payload)) = tagged(value) {
+				let payload = match payload {
+					Payload::Int(v) => serde_json::Value::from(v),
+					Payload::Float(v) => float(v),
+					Payload::Str(s) => serde_json::Value::String(s),
+					Payload::Pair(a, b) => serde_json::Value::from(vec![a, b]),
+				};
+				let mut map = Map::new();
+				map.insert(tag.to_string(), payload);
+				return Ok(serde_json::Value::Object(map));
+			}
+
+			let json = match value.get_type() {
+				VALUE_TYPE::T_UNDEFINED | VALUE_TYPE::T_NULL => serde_json::Value::Null,
+				VALUE_TYPE::T_BOOL => serde_json::Value::Bool(value.to_bool() == Some(true)),
+				VALUE_TYPE::T_INT => serde_json::Value::from(value.to_int().unwrap_or_default()),
+				VALUE_TYPE::T_FLOAT => float(value.to_float().unwrap_or_default()),
+				VALUE_TYPE::T_STRING => serde_json::Value::String(value.as_string().unwrap_or_default()),
+				VALUE_TYPE::T_ARRAY => {
+					let mut items = Vec::with_capacity(value.len());
+					for (i, item) in value.values().enumerate() {
+						items.push(serde_json::Value::try_from(&item).map_err(|e| e.at_index(i))?);
+					}
+					serde_json::Value::Array(items)
+				},
+				VALUE_TYPE::T_MAP => {
+					let items = value.items();
+					let mut map = Map::new();
+					for (key, item) in &items {
+						let key = key_name(key);
+						let item = serde_json::Value::try_from(item).map_err(|e| e.at_key(&key))?;
+						map.insert(key, item);
+					}
+					if is_reserved(&items) {
+						let mut escaped = Map::new();
+						escaped.insert("$map".to_string(), serde_json::Value::Object(map));
+						map = escaped;
+					}
+					serde_json::Value::Object(map)
+				},
+				VALUE_TYPE::T_OBJECT => {
+					let mut copy = value.clone();
+					copy.isolate();
+					if copy.is_array() || copy.is_map() {
+						return serde_json::Value::try_from(&copy);
+					}
+					return Err(ConversionError::new("JSON data", value));
+				},
+				_ => return Err(ConversionError::new("JSON data", value)),
+			};
+			Ok(json)
+		}
+	}
+
+	impl TryFrom<Value> for serde_json::Value {
+		type Error = ConversionError;
+
+		fn try_from(value: Value) -> Result<Self, ConversionError> {
+			serde_json::Value::try_from(&value)
+		}
+	}
+
+	// Non-finite floats become `null` like in `serde_json`.
+	fn float(v: f64) -> serde_json::Value {
+		Number::from_f64(v).map(serde_json::Value::Number).unwrap_or_default()
+	}
+
+	// Plain map, its items are restored as usual.
+	fn plain(map: &Map<String, serde_json::Value>) -> Value {
+		let mut v = Value::map();
+		for (key, item) in map {
+			v.set_item(key.as_str(), Value::from(item));
+		}
+		v
+	}
+
+	// Restore a Sciter-specific value from the reserved key and its payload.
+	fn untagged(tag: &str, payload: &serde_json::Value) -> Option<Value> {
+		let value = match tag {
+			"$symbol" => Value::symbol(payload.as_str()?),
+			"$error" => Value::error(payload.as_str()?),
+			"$bytes" => Value::from(base64_decode(payload.as_str()?)?.as_slice()),
+			"$color" => {
+				let v = payload.as_u64()?;
+				if v > u32::MAX as u64 {
+					return None;
+				}
+				Value::color(v as u32)
+			},
+			"$date" => Value::date(parse_date(payload.as_str()?)?, true),
+			"$currency" => Value::currency(payload.as_str()?.parse::<Currency>().ok()?),
+			"$length" => Value::length(payload.as_str()?.parse().ok()?),
+			"$range" => {
+				let pair = payload.as_array()?;
+				let int = |v: &serde_json::Value| v.as_i64().filter(|&v| v >= i32::MIN as i64 && v <= i32::MAX as i64);
+				if pair.len() != 2 {
+					return None;
+				}
+				Value::range(Range::new(int(&pair[0])? as i32, int(&pair[1])? as i32))
+			},
+			"$duration" => Value::duration(payload.as_f64()?),
+			"$angle" => Value::angle(payload.as_f64()?),
+			"$map" => plain(payload.as_object()?),
+			_ => return None,
+		};
+		Some(value)
+	}
+}
@@ -68,7 +68,6 @@ folder for more complex usage and module-level sections for the guides about:
 #![allow(clippy::unreadable_literal)] // C++ SDK constants
 #![allow(clippy::upper_case_acronyms)]// C++ SDK constants
 #![allow(clippy::deprecated_semver)]  // `#[deprecated(since="Sciter 4.4.3.24")]` is not a semver format.
-#![allow(clippy::result_unit_err)]		// Sciter returns BOOL, but `Result<(), ()>` is more strict even without error description.
 // #![allow(clippy::cast_ptr_alignment)] // 0.0.195 only
 
 
@@ -78,6 +77,9 @@ folder for more complex usage and module-level sections for the guides about:
 #[macro_use] extern crate objc;
 #[macro_use] extern crate lazy_static;
 
+#[cfg(feature = "json")]
+extern crate serde_json;
+
 
 #[macro_use] pub mod macros;
 
@@ -90,8 +92,13 @@ pub use capi::scdef::{GFX_LAYER, SCRIPT_RUNTIME_FEATURES};
 /* Rust interface */
 mod platform;
 mod eventhandler;
+mod json;
+
+#[cfg(feature = "emulated")]
+mod emulated;
 
 pub mod dom;
+pub mod error;
 pub mod graphics;
 pub mod host;
 pub mod om;
@@ -105,6 +112,7 @@ pub mod windowless;
 
 pub use dom::Element;
 pub use dom::event::EventHandler;
+pub use error::Error;
 pub use host::{Archive, Host, HostHandler};
 pub use value::{Value, FromValue};
 pub use window::Window;
@@ -129,7 +137,7 @@ pub use capi::scapi::{ISciterAPI};
 use capi::scgraphics::SciterGraphicsAPI;
 use capi::screquest::SciterRequestAPI;
 
-#[cfg(windows)]
+#[cfg(all(windows, not(feature = "emulated")))]
 mod ext {
 	// Note:
 	// Sciter 4.x shipped with universal "sciter.dll" library for different builds:
@@ -217,7 +225,7 @@ mod ext {
 	}
 }
 
-#[cfg(all(feature = "dynamic", unix))]
+#[cfg(all(feature = "dynamic", unix, not(feature = "emulated")))]
 mod ext {
   #![allow(non_snake_case, non_camel_case_types)]
   extern crate libc;
@@ -362,7 +370,7 @@ mod ext {
 }
 
 
-#[cfg(all(target_os = "linux", not(feature = "dynamic")))]
+#[cfg(all(target_os = "linux", not(feature = "dynamic"), not(feature = "emulated")))]
 mod ext {
 	// Note:
 	// Since 4.1.4 library name has been changed to "libsciter-gtk" (without 32/64 suffix).
@@ -372,12 +380,18 @@ mod ext {
 	extern "system" { pub fn SciterAPI() -> *const ::capi::scapi::ISciterAPI;	}
 }
 
-#[cfg(all(target_os = "macos", target_arch = "x86_64", not(feature = "dynamic")))]
+#[cfg(all(target_os = "macos", target_arch = "x86_64", not(feature = "dynamic"), not(feature = "emulated")))]
 mod ext {
 	#[link(name = "libsciter", kind = "dylib")]
 	extern "system" { pub fn SciterAPI() -> *const ::capi::scapi::ISciterAPI;	}
 }
 
+#[cfg(feature = "emulated")]
+mod ext {
+	// In-process Value API, see the "emulated" feature.
+	pub use emulated::SciterAPI;
+}
+
 /// Getting ISciterAPI reference, can be used for manual API calling.
 #[doc(hidden)]
 #[allow(non_snake_case)]
@@ -459,12 +473,17 @@ lazy_static! {
 /// }
 /// ```
 pub fn set_library(custom_path: &str) -> ::std::result::Result<(), String> {
-  #[cfg(not(feature = "dynamic"))]
+  #[cfg(all(not(feature = "dynamic"), not(feature = "emulated")))]
   fn set_impl(_: &str) -> ::std::result::Result<(), String> {
     Err("Don't use `sciter::set_library()` in static builds.\n  Build with the feature \"dynamic\" instead.".to_owned())
   }
 
-  #[cfg(feature = "dynamic")]
+  #[cfg(feature = "emulated")]
+  fn set_impl(_: &str) -> ::std::result::Result<(), String> {
+    Err("Don't use `sciter::set_library()` with the emulated Sciter API.\n  Build without the feature \"emulated\" instead.".to_owned())
+  }
+
+  #[cfg(all(feature = "dynamic", not(feature = "emulated")))]
   fn set_impl(path: &str) -> ::std::result::Result<(), String> {
     unsafe {
       ext::CUSTOM_DLL_PATH = Some(path.to_owned());
@@ -578,7 +597,7 @@ pub enum RuntimeOptions<'a> {
 }
 
 /// Set various global Sciter engine options, see the [`RuntimeOptions`](enum.RuntimeOptions.html).
-pub fn set_options(options: RuntimeOptions) -> std::result::Result<(), ()> {
+pub fn set_options(options: RuntimeOptions) -> std::result::Result<(), Error> {
 	use RuntimeOptions::*;
 	use capi::scdef::SCITER_RT_OPTIONS::*;
 	let (option, value) = match options {
@@ -594,14 +613,14 @@ pub fn set_options(options: RuntimeOptions) -> std::result::Result<(), ()> {
 		LogicalPixel(enable) => (SCITER_SET_PX_AS_DIP, enable as usize),
 
     LibraryPath(path) => {
-      return set_library(path).map_err(|_|());
+      return set_library(path).map_err(Error::Library);
     }
 	};
 	let ok = (_API.SciterSetOption)(std::ptr::null_mut(), option, value);
 	if ok != 0 {
 		Ok(())
 	} else {
-		Err(())
+		Err(Error::Failed { api: "SciterSetOption" })
 	}
 }
 
@@ -620,7 +639,7 @@ pub fn set_variable(path: &str, value: Value) -> dom::Result<()> {
 	if ok == dom::SCDOM_RESULT::OK {
 		Ok(())
 	} else {
-		Err(ok)
+		Err(Error::Dom { api: "SciterSetVariable", code: ok })
 	}
 }
 
@@ -635,6 +654,6 @@ pub fn get_variable(path: &str) -> dom::Result<Value> {
 	if ok == dom::SCDOM_RESULT::OK {
 		Ok(value)
 	} else {
-		Err(ok)
+		Err(Error::Dom { api: "SciterGetVariable", code: ok })
 	}
 }
@@ -144,7 +144,7 @@ macro_rules! dispatch_script_call {
 
 	(
 		$(
-			fn $name:ident ( $( $argt:ident ),* );
+			fn $name:ident ( $( $argt:ty ),* );
 		 )*
 	) => {
 
@@ -171,12 +171,12 @@ macro_rules! dispatch_script_call {
 						let rv = self.$name(
 							$(
 								{
-									match $crate::FromValue::from_value(&argv[_i]) {
-										Some(arg) => { _i += 1; arg },
-										None => {
+									match <$argt as $crate::FromValue>::try_from_value(&argv[_i]) {
+										Ok(arg) => { _i += 1; arg },
+										Err(e) => {
 											// invalid type
-											return Some($crate::Value::error(&format!("{} error: invalid type of {} argument ({} expected, {:?} provided).",
-												stringify!($name), _i, stringify!($argt), argv[_i])));
+											return Some($crate::Value::error(&format!("{} error: invalid type of {} argument: {}.",
+												stringify!($name), _i, e)));
 										},
 									}
 								}
@@ -262,3 +262,148 @@ macro_rules! varray {
     }
   };
 }
+
+/// Creates a `sciter::Value` from a JSON-like literal.
+///
+/// Supports nested maps and arrays, `null`, `undefined` and `nothing`,
+/// typed constructors of [`Value`](value/struct.Value.html) (`color`, `duration`, `angle`, `date`,
+/// `currency`, `length`, `range`, `symbol` and `error`) and any `Into<Value>` expression.
+///
+/// Map keys are expressions too; an expression containing a colon (like a path) must be parenthesized,
+/// as well as a value expression that starts with a call and continues after it (`(f(x) + 1)`).
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate sciter;
+/// # fn main() {
+/// let b = "b";
+/// let v: sciter::Value = value!({
+///   "a": [1, 2.0, { b: null }],
+///   "c": color(0xFF_00_00),
+///   "d": undefined,
+///   (b.to_uppercase()): [],
+/// });
+/// assert!(v.is_map());
+/// assert_eq!(v["a"][2]["b"], sciter::Value::null());
+/// assert!(v["c"].is_color());
+/// assert_eq!(v.len(), 4);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! value {
+  // literals
+  (null) => { $crate::Value::null() };
+  (undefined) => { $crate::Value::new() };
+  (nothing) => { $crate::Value::nothing() };
+
+  // containers
+  ([]) => { $crate::Value::array(0) };
+  ([ $($tt:tt)+ ]) => { value!(@array [] $($tt)+) };
+  ({}) => { $crate::Value::map() };
+  ({ $($tt:tt)+ }) => {
+    {
+      let mut _v = $crate::Value::map();
+      value!(@object _v () ($($tt)+) ($($tt)+));
+      _v
+    }
+  };
+
+  // typed constructors or a function call
+  ($name:ident ( $($args:tt)* )) => { value!(@call $name ($($args)*)) };
+
+  // any other expression
+  ($other:expr) => { $crate::Value::from($other) };
+
+
+  // typed constructors
+  (@call color ($($args:tt)*)) => { $crate::Value::color($($args)*) };
+  (@call duration ($($args:tt)*)) => { $crate::Value::duration($($args)*) };
+  (@call angle ($($args:tt)*)) => { $crate::Value::angle($($args)*) };
+  (@call date ($($args:tt)*)) => { $crate::Value::date($($args)*) };
+  (@call currency ($($args:tt)*)) => { $crate::Value::currency($($args)*) };
+  (@call length ($($args:tt)*)) => { $crate::Value::length($($args)*) };
+  (@call range ($($args:tt)*)) => { $crate::Value::range($($args)*) };
+  (@call symbol ($($args:tt)*)) => { $crate::Value::symbol($($args)*) };
+  (@call error ($($args:tt)*)) => { $crate::Value::error($($args)*) };
+  (@call $name:ident ($($args:tt)*)) => { $crate::Value::from($name($($args)*)) };
+
+
+  // array: done
+  (@array [$($items:expr,)*]) => {
+    {
+      let mut _v = $crate::Value::array(0);
+      $(
+        _v.push($items);
+      )*
+      _v
+    }
+  };
+  (@array [$($items:expr),*]) => { value!(@array [$($items,)*]) };
+
+  // array: next item is a literal, a container or a call
+  (@array [$($items:expr,)*] null $($rest:tt)*) => { value!(@array [$($items,)* value!(null)] $($rest)*) };
+  (@array [$($items:expr,)*] undefined $($rest:tt)*) => { value!(@array [$($items,)* value!(undefined)] $($rest)*) };
+  (@array [$($items:expr,)*] nothing $($rest:tt)*) => { value!(@array [$($items,)* value!(nothing)] $($rest)*) };
+  (@array [$($items:expr,)*] [$($array:tt)*] $($rest:tt)*) => { value!(@array [$($items,)* value!([$($array)*])] $($rest)*) };
+  (@array [$($items:expr,)*] {$($map:tt)*} $($rest:tt)*) => { value!(@array [$($items,)* value!({$($map)*})] $($rest)*) };
+  (@array [$($items:expr,)*] $name:ident ($($args:tt)*) $($rest:tt)*) => { value!(@array [$($items,)* value!(@call $name ($($args)*))] $($rest)*) };
+
+  // array: next item is an expression
+  (@array [$($items:expr,)*] $next:expr, $($rest:tt)*) => { value!(@array [$($items,)* value!($next),] $($rest)*) };
+  (@array [$($items:expr,)*] $last:expr) => { value!(@array [$($items,)* value!($last)]) };
+
+  // array: comma after the item
+  (@array [$($items:expr),*] , $($rest:tt)*) => { value!(@array [$($items,)*] $($rest)*) };
+
+
+  // map: done
+  (@object $map:ident () () ()) => {};
+
+  // map: insert the entry
+  (@object $map:ident [$($key:tt)+] ($value:expr) , $($rest:tt)*) => {
+    $map.set_item($($key)+, $value);
+    value!(@object $map () ($($rest)*) ($($rest)*));
+  };
+  (@object $map:ident [$($key:tt)+] ($value:expr)) => {
+    $map.set_item($($key)+, $value);
+  };
+
+  // map: next value is a literal, a container or a call
+  (@object $map:ident ($($key:tt)+) (: null $($rest:tt)*) $copy:tt) => {
+    value!(@object $map [$($key)+] (value!(null)) $($rest)*);
+  };
+  (@object $map:ident ($($key:tt)+) (: undefined $($rest:tt)*) $copy:tt) => {
+    value!(@object $map [$($key)+] (value!(undefined)) $($rest)*);
+  };
+  (@object $map:ident ($($key:tt)+) (: nothing $($rest:tt)*) $copy:tt) => {
+    value!(@object $map [$($key)+] (value!(nothing)) $($rest)*);
+  };
+  (@object $map:ident ($($key:tt)+) (: [$($array:tt)*] $($rest:tt)*) $copy:tt) => {
+    value!(@object $map [$($key)+] (value!([$($array)*])) $($rest)*);
+  };
+  (@object $map:ident ($($key:tt)+) (: {$($inner:tt)*} $($rest:tt)*) $copy:tt) => {
+    value!(@object $map [$($key)+] (value!({$($inner)*})) $($rest)*);
+  };
+  (@object $map:ident ($($key:tt)+) (: $name:ident ($($args:tt)*) $($rest:tt)*) $copy:tt) => {
+    value!(@object $map [$($key)+] (value!(@call $name ($($args)*))) $($rest)*);
+  };
+
+  // map: next value is an expression
+  (@object $map:ident ($($key:tt)+) (: $value:expr , $($rest:tt)*) $copy:tt) => {
+    value!(@object $map [$($key)+] (value!($value)) , $($rest)*);
+  };
+  (@object $map:ident ($($key:tt)+) (: $value:expr) $copy:tt) => {
+    value!(@object $map [$($key)+] (value!($value)));
+  };
+
+  // map: parenthesized key
+  (@object $map:ident () (($key:expr) : $($rest:tt)*) $copy:tt) => {
+    value!(@object $map ($key) (: $($rest)*) (: $($rest)*));
+  };
+
+  // map: munch a token into the key
+  (@object $map:ident ($($key:tt)*) ($tt:tt $($rest:tt)*) $copy:tt) => {
+    value!(@object $map ($($key)* $tt) ($($rest)*) ($($rest)*));
+  };
+}
@@ -273,9 +273,9 @@ mod tests {
 	#[test]
 	fn s2w_test() {
 		let v = s2vec("");
-		assert_eq!(v, []);
+		assert_eq!(v, [0_u16; 0]);
 
-		assert_eq!(s2vec(""), []);
+		assert_eq!(s2vec(""), [0_u16; 0]);
 
 		assert_eq!(s2vec("A"), ['A' as u16, 0]);
 
@@ -283,6 +283,6 @@ mod tests {
 
 		let (cs, n) = s2wn!("");
 		assert_eq!(n, 0);
-		assert_eq!(cs, []);
+		assert_eq!(cs, [0_u16; 0]);
 	}
 }
@@ -101,6 +101,16 @@ assert_eq!(array.len(), 3);
 # }
 ```
 
+Nested data can be written with the [`value!`](../macro.value.html) macro in a JSON-like syntax:
+
+```
+# #[macro_use] extern crate sciter;
+# fn main() {
+let v = value!({ "items": [1, 2.0, null], "color": color(0xff0000) });
+assert_eq!(v["items"].len(), 3);
+# }
+```
+
 To access its contents you should use one of [`to_`](struct.Value.html#method.to_int) methods:
 
 ```
@@ -160,8 +170,9 @@ assert!(v.get_item("one").is_int());
 use ::{_API};
 
 use capi::sctypes::*;
-use capi::scvalue::{VALUE_UNIT_TYPE_STRING, VALUE_UNIT_TYPE_OBJECT, VALUE_UNIT_UNDEFINED};
-pub use capi::scvalue::{VALUE_RESULT, VALUE_STRING_CVT_TYPE, VALUE_TYPE};
+use capi::scvalue::{VALUE_UNIT_TYPE_OBJECT, VALUE_UNIT_UNDEFINED};
+pub use capi::scvalue::{VALUE_RESULT, VALUE_STRING_CVT_TYPE, VALUE_TYPE, VALUE_UNIT_TYPE_LENGTH};
+pub use capi::scvalue::{VALUE_UNIT_TYPE_ARRAY, VALUE_UNIT_TYPE_STRING};
 use capi::scvalue::VALUE;
 use ::om::IAsset;
 
@@ -250,6 +261,62 @@ impl Value {
 		return me;
 	}
 
+	/// Make Sciter [date](https://sciter.com/docs/content/script/Date.htm) value.
+	///
+	/// Sciter stores dates as `FILETIME` (100ns intervals since January 1, 1601);
+	/// `is_utc` tells whether `val` is an UTC time or a local wall-clock one.
+	///
+	/// Times beyond the `FILETIME` range (about 29 000 years around 1601) are saturated to its ends.
+	pub fn date(val: ::std::time::SystemTime, is_utc: bool) -> Value {
+		let mut me = Value::new();
+		(_API.ValueInt64DataSet)(me.as_ptr(), filetime_from(val), VALUE_TYPE::T_DATE as u32, is_utc as UINT);
+		return me;
+	}
+
+	/// Make Sciter currency value.
+	pub fn currency(val: Currency) -> Value {
+		let mut me = Value::new();
+		(_API.ValueInt64DataSet)(me.as_ptr(), val.raw(), VALUE_TYPE::T_CURRENCY as u32, 0);
+		return me;
+	}
+
+	/// Make Sciter [length](https://sciter.com/docs/content/script/Length.htm) value.
+	pub fn length(val: Length) -> Value {
+		let mut me = Value::new();
+		(_API.ValueFloatDataSet)(me.as_ptr(), val.value(), VALUE_TYPE::T_LENGTH as u32, val.unit() as UINT);
+		return me;
+	}
+
+	/// Make Sciter `N..M` range value.
+	pub fn range(val: Range) -> Value {
+		let mut me = Value::new();
+		let packed = (val.start as u32 as i64) | ((val.end as u32 as i64) << 32);
+		(_API.ValueInt64DataSet)(me.as_ptr(), packed, VALUE_TYPE::T_RANGE as u32, 0);
+		return me;
+	}
+
+	/// Make a native function from a closure with typed parameters.
+	///
+	/// Script arguments are converted via [`FromValue`](trait.FromValue.html);
+	/// a wrong number of arguments or a failed conversion is returned to script as an error value,
+	/// like [`dispatch_script_call!`](../macro.dispatch_script_call.html) does for methods.
+	///
+	/// ```
+	/// use sciter::Value;
+	///
+	/// let add = Value::from_fn(|a: i32, b: i32| a + b);
+	/// assert_eq!(add.call(None, &[Value::from(1), Value::from(2)], None), Ok(Value::from(3)));
+	///
+	/// let e = add.call(None, &[Value::from(1)], None).unwrap_err();
+	/// assert!(e.value().is_error_string());
+	/// ```
+	pub fn from_fn<F, Args>(f: F) -> Value
+	where
+		F: NativeFunction<Args>,
+	{
+		Value::from(move |args: &[Value]| f.call_native(args))
+	}
+
 	/// Parse a json string into value. Returns the number of chars left unparsed in case of error.
 	pub fn parse(val: &str) -> Result<Value, usize> {
 		return Value::parse_as(val, VALUE_STRING_CVT_TYPE::JSON_LITERAL);
@@ -429,6 +496,331 @@ impl Value {
 		return result;
 	}
 
+	/// A lazy iterator visiting all key-value pairs in order.
+	///
+	/// Unlike [`items`](#method.items) it does not collect the pairs beforehand.
+	///
+	/// The iterator element type is `(Value, Value)`.
+	pub fn iter_items(&self) -> ItemIterator<'_> {
+		ItemIterator {
+			base: self,
+			index: 0,
+			count: self.len(),
+		}
+	}
+
+	/// Returns `true` if the map-like value contains the given key.
+	///
+	/// Like [`get_item`](#method.get_item), treats a key holding `undefined` as absent.
+	pub fn contains_key<T: Into<Value>>(&self, key: T) -> bool {
+		!self.get_item(key).is_undefined()
+	}
+
+	/// A copy of the map without the given key.
+	///
+	/// Sciter has no API to delete a map key, so this builds a new map from the rest of the items;
+	/// `self` and other handles to it are left intact. Non-map values are returned as is.
+	pub fn without_item<T: Into<Value>>(&self, key: T) -> Value {
+		if !self.is_map() {
+			return self.clone();
+		}
+		let key = key.into();
+		let mut rest = Value::map();
+		for (k, v) in self.iter_items() {
+			if !same_key(&k, &key) {
+				rest.set_item(k, v);
+			}
+		}
+		rest
+	}
+
+	/// Gets the given key's entry in the map for in-place manipulation,
+	/// see [`HashMap::entry`](https://doc.rust-lang.org/std/collections/struct.HashMap.html#method.entry).
+	/// A key holding `undefined` is treated as vacant.
+	///
+	/// ```
+	/// use sciter::Value;
+	///
+	/// let mut counters = Value::map();
+	/// for word in ["a", "b", "a"].iter() {
+	///   counters.entry(*word).and_modify(|n| *n = Value::from(n.to_int().unwrap() + 1)).or_insert(1);
+	/// }
+	/// assert_eq!(counters["a"], Value::from(2));
+	/// assert_eq!(counters["b"], Value::from(1));
+	/// ```
+	///
+	/// Sciter values can not be borrowed from the map, so the entry holds a copy of the value.
+	/// [`or_insert`](enum.Entry.html#method.or_insert) and friends return an [`EntryRef`](struct.EntryRef.html) guard
+	/// which stores the value back to the map when dropped, if it was modified through the guard:
+	///
+	/// ```
+	/// use sciter::Value;
+	///
+	/// let mut map = Value::map();
+	/// *map.entry("answer").or_insert(0) = Value::from(42);
+	/// assert_eq!(map["answer"], Value::from(42));
+	/// ```
+	pub fn entry<T: Into<Value>>(&mut self, key: T) -> Entry<'_> {
+		let key = key.into();
+		let value = self.get_item(key.clone());
+		if value.is_undefined() {
+			Entry::Vacant(VacantEntry { map: self, key })
+		} else {
+			Entry::Occupied(OccupiedEntry { map: self, key, value })
+		}
+	}
+
+	/// Look up a nested value by a [JSON pointer](https://tools.ietf.org/html/rfc6901), e.g. `/user/addresses/2/city`.
+	///
+	/// Map keys and array indices are traversed the same way; `~1` and `~0` escape `/` and `~` in keys.
+	/// Returns `None` if the pointer is malformed or any step of it is missing.
+	///
+	/// The result is a copy of the nested value. Arrays and maps are shared by reference,
+	/// so changes of a returned container are visible in `self`;
+	/// to replace a nested value use [`set_path`](#method.set_path) or [`pointer_mut`](#method.pointer_mut).
+	///
+	/// ```
+	/// use sciter::Value;
+	///
+	/// let v: Value = "{user: {addresses: [{}, {}, {city: 'Paris'}]}}".parse().unwrap();
+	/// assert_eq!(v.pointer("/user/addresses/2/city"), Some(Value::from("Paris")));
+	/// assert_eq!(v.pointer("/user/phones/0"), None);
+	///
+	/// let mut addresses = v.pointer("/user/addresses").unwrap();
+	/// addresses.push(Value::map());
+	/// assert_eq!(v["user"]["addresses"].len(), 4);
+	/// ```
+	pub fn pointer(&self, pointer: &str) -> Option<Value> {
+		let steps = PathStep::parse_pointer(pointer)?;
+		self.lookup(&steps)
+	}
+
+	/// Look up a nested value by a JSON pointer for modification.
+	///
+	/// Returns a [`PathRef`](struct.PathRef.html) guard which dereferences to a copy of the nested value
+	/// and, if it was modified, stores it back by the same path when dropped.
+	///
+	/// ```
+	/// use sciter::Value;
+	///
+	/// let mut v: Value = "{user: {name: 'Bob', age: 30}}".parse().unwrap();
+	/// *v.pointer_mut("/user/age").unwrap() = Value::from(31);
+	/// assert_eq!(v.pointer("/user/age"), Some(Value::from(31)));
+	/// ```
+	pub fn pointer_mut(&mut self, pointer: &str) -> Option<PathRef<'_>> {
+		let steps = PathStep::parse_pointer(pointer)?;
+		self.lookup_mut(steps)
+	}
+
+	/// Look up a nested value by a dotted path (e.g. `user.addresses[2].city`) or by a JSON pointer.
+	///
+	/// Numeric segments (`addresses.2`) index arrays as well as bracketed ones.
+	/// See [`pointer`](#method.pointer) for details.
+	///
+	/// ```
+	/// use sciter::Value;
+	///
+	/// let v: Value = "{user: {addresses: [{}, {}, {city: 'Paris'}]}}".parse().unwrap();
+	/// assert_eq!(v.get_path("user.addresses[2].city"), Some(Value::from("Paris")));
+	/// assert_eq!(v.get_path("user.addresses.2.city"), Some(Value::from("Paris")));
+	/// ```
+	pub fn get_path(&self, path: &str) -> Option<Value> {
+		if path.starts_with('/') {
+			return self.pointer(path);
+		}
+		let steps = PathStep::parse_dotted(path)?;
+		self.lookup(&steps)
+	}
+
+	/// Look up a nested value by a dotted path or by a JSON pointer for modification.
+	///
+	/// See [`pointer_mut`](#method.pointer_mut) for details.
+	///
+	/// ```
+	/// use sciter::Value;
+	///
+	/// let mut v: Value = "{user: {addresses: [{city: 'Paris'}]}}".parse().unwrap();
+	/// *v.get_path_mut("user.addresses[0].city").unwrap() = Value::from("Berlin");
+	/// assert_eq!(v.get_path("user.addresses[0].city"), Some(Value::from("Berlin")));
+	/// ```
+	pub fn get_path_mut(&mut self, path: &str) -> Option<PathRef<'_>> {
+		let steps = if path.starts_with('/') {
+			PathStep::parse_pointer(path)?
+		} else {
+			PathStep::parse_dotted(path)?
+		};
+		self.lookup_mut(steps)
+	}
+
+	/// Set a nested value by a dotted path or by a JSON pointer, creating missing containers on the way.
+	///
+	/// A missing (`undefined` or `null`) step becomes an array if the next segment is an index
+	/// and a map otherwise; the `-` pointer segment appends to an array.
+	///
+	/// Returns `VALUE_RESULT::BAD_PARAMETER` for a malformed path and
+	/// `VALUE_RESULT::INCOMPATIBLE_TYPE` if some step is neither a container nor missing.
+	///
+	/// ```
+	/// use sciter::Value;
+	///
+	/// let mut v = Value::new();
+	/// v.set_path("user.addresses[0].city", "Paris").unwrap();
+	/// v.set_path("/user/addresses/-", "Berlin").unwrap();
+	/// assert!(v.is_map());
+	/// assert_eq!(v["user"]["addresses"].len(), 2);
+	/// assert_eq!(v.get_path("user.addresses[1]"), Some(Value::from("Berlin")));
+	/// ```
+	pub fn set_path<T: Into<Value>>(&mut self, path: &str, value: T) -> Result<(), VALUE_RESULT> {
+		let steps = if path.starts_with('/') {
+			PathStep::parse_pointer(path)
+		} else {
+			PathStep::parse_dotted(path)
+		};
+		let steps = steps.ok_or(VALUE_RESULT::BAD_PARAMETER)?;
+		self.assign_path(&steps, value.into())
+	}
+
+	/// Structural difference between `self` and the `other` value.
+	///
+	/// Maps are compared key by key and arrays index by index, recursively;
+	/// any other mismatch is reported as a change of the whole item.
+	/// Each change is addressed by a [JSON pointer](#method.pointer), the empty one stands for the value itself.
+	///
+	/// ```
+	/// use sciter::Value;
+	/// use sciter::value::Change;
+	///
+	/// let old: Value = "{name: 'Bob', tags: ['a', 'b'], age: 30}".parse().unwrap();
+	/// let new: Value = "{name: 'Bob', tags: ['a'], age: 31, city: 'Paris'}".parse().unwrap();
+	/// assert_eq!(old.diff(&new), vec![
+	///   Change::Removed { path: "/tags/1".to_string(), value: Value::from("b") },
+	///   Change::Changed { path: "/age".to_string(), old: Value::from(30), new: Value::from(31) },
+	///   Change::Added { path: "/city".to_string(), value: Value::from("Paris") },
+	/// ]);
+	/// assert!(new.diff(&new).is_empty());
+	/// ```
+	pub fn diff(&self, other: &Value) -> Vec<Change> {
+		let mut changes = Vec::new();
+		self.diff_into(other, &mut String::new(), &mut changes);
+		changes
+	}
+
+	fn diff_into(&self, other: &Value, path: &mut String, changes: &mut Vec<Change>) {
+		let at = |path: &mut String, segment: &str| {
+			let len = path.len();
+			path.push('/');
+			path.push_str(&segment.replace('~', "~0").replace('/', "~1"));
+			len
+		};
+		if self.is_map() && other.is_map() {
+			for (key, value) in self.iter_items() {
+				let len = at(path, &key_text(&key));
+				let theirs = other.get_item(key);
+				if theirs.is_undefined() {
+					changes.push(Change::Removed { path: path.clone(), value });
+				} else {
+					value.diff_into(&theirs, path, changes);
+				}
+				path.truncate(len);
+			}
+			for (key, value) in other.iter_items() {
+				if !self.contains_key(key.clone()) {
+					let len = at(path, &key_text(&key));
+					changes.push(Change::Added { path: path.clone(), value });
+					path.truncate(len);
+				}
+			}
+		} else if self.is_array() && other.is_array() {
+			let (mine, theirs) = (self.len(), other.len());
+			for index in 0..mine.max(theirs) {
+				let len = at(path, &index.to_string());
+				if index >= theirs {
+					changes.push(Change::Removed { path: path.clone(), value: self.get(index) });
+				} else if index >= mine {
+					changes.push(Change::Added { path: path.clone(), value: other.get(index) });
+				} else {
+					self.get(index).diff_into(&other.get(index), path, changes);
+				}
+				path.truncate(len);
+			}
+		} else if self != other {
+			changes.push(Change::Changed { path: path.clone(), old: self.clone(), new: other.clone() });
+		}
+	}
+
+	/// Copy of the nested value.
+	fn lookup(&self, steps: &[PathStep]) -> Option<Value> {
+		let mut current = self.clone();
+		for step in steps {
+			current = current.child(step)?;
+		}
+		Some(current)
+	}
+
+	/// Copy of the nested value in a guard that stores it back.
+	fn lookup_mut(&mut self, steps: Vec<PathStep>) -> Option<PathRef<'_>> {
+		let value = self.lookup(&steps)?;
+		Some(PathRef { root: self, steps, value, dirty: false })
+	}
+
+	/// Immediate sub-element addressed by a path step.
+	fn child(&self, step: &PathStep) -> Option<Value> {
+		let item = if self.is_array() || self.is_object_array() {
+			match *step {
+				PathStep::Index(index) if index < self.len() => self.get(index),
+				_ => return None,
+			}
+		} else if self.is_map() || self.is_object_map() || self.is_object() {
+			match *step {
+				PathStep::Key(ref key) => self.get_item(key.as_str()),
+				PathStep::Index(index) => self.get_item(index.to_string()),
+				PathStep::Append => return None,
+			}
+		} else {
+			return None;
+		};
+		if item.is_undefined() { None } else { Some(item) }
+	}
+
+	/// Replace the nested value, creating the missing containers.
+	fn assign_path(&mut self, steps: &[PathStep], value: Value) -> Result<(), VALUE_RESULT> {
+		let (step, rest) = match steps.split_first() {
+			Some(split) => split,
+			None => {
+				*self = value;
+				return Ok(());
+			}
+		};
+		if self.is_undefined() || self.is_null() {
+			*self = match *step {
+				PathStep::Key(_) => Value::map(),
+				_ => Value::array(0),
+			};
+		}
+		if self.is_array() || self.is_object_array() {
+			let index = match *step {
+				PathStep::Index(index) => index,
+				PathStep::Append => self.len(),
+				PathStep::Key(_) => return Err(VALUE_RESULT::INCOMPATIBLE_TYPE),
+			};
+			let mut item = if index < self.len() { self.get(index) } else { Value::new() };
+			item.assign_path(rest, value)?;
+			self.set(index, item);
+		} else if self.is_map() || self.is_object_map() || self.is_object() {
+			let key = match *step {
+				PathStep::Key(ref key) => key.clone(),
+				PathStep::Index(index) => index.to_string(),
+				PathStep::Append => return Err(VALUE_RESULT::INCOMPATIBLE_TYPE),
+			};
+			let mut item = self.get_item(key.as_str());
+			item.assign_path(rest, value)?;
+			self.set_item(key, item);
+		} else {
+			return Err(VALUE_RESULT::INCOMPATIBLE_TYPE);
+		}
+		Ok(())
+	}
+
 	/// Value to integer.
 	pub fn to_int(&self) -> Option<i32> {
 		let mut val = 0i32;
@@ -483,6 +875,54 @@ impl Value {
 		}
 	}
 
+	/// Value to date.
+	pub fn to_date(&self) -> Option<::std::time::SystemTime> {
+		if !self.is_date() {
+			return None;
+		}
+		let mut val = 0i64;
+		match (_API.ValueInt64Data)(self.as_cptr(), &mut val) {
+			VALUE_RESULT::OK => filetime_to(val),
+			_ => None
+		}
+	}
+
+	/// Value to currency.
+	pub fn to_currency(&self) -> Option<Currency> {
+		if !self.is_currency() {
+			return None;
+		}
+		let mut val = 0i64;
+		match (_API.ValueInt64Data)(self.as_cptr(), &mut val) {
+			VALUE_RESULT::OK => Some(Currency::from_raw(val)),
+			_ => None
+		}
+	}
+
+	/// Value to length, keeping its units.
+	pub fn to_length(&self) -> Option<Length> {
+		if !self.is_length() {
+			return None;
+		}
+		let mut val = 0f64;
+		match (_API.ValueFloatData)(self.as_cptr(), &mut val) {
+			VALUE_RESULT::OK => Length::from_raw(val, self.data.u),
+			_ => None
+		}
+	}
+
+	/// Value to range.
+	pub fn to_range(&self) -> Option<Range> {
+		if !self.is_range() {
+			return None;
+		}
+		let mut val = 0i64;
+		match (_API.ValueInt64Data)(self.as_cptr(), &mut val) {
+			VALUE_RESULT::OK => Some(Range::new(val as u32 as i32, (val >> 32) as u32 as i32)),
+			_ => None
+		}
+	}
+
 	/// Value as string for `T_STRING` type.
 	pub fn as_string(&self) -> Option<String> {
 		let mut s = 0 as LPCWSTR;
@@ -499,6 +939,61 @@ impl Value {
 		return self.as_string().unwrap();
 	}
 
+	/// Value to string using the specified conversion, a counterpart of [`parse_as`](#method.parse_as).
+	///
+	/// ```
+	/// # use sciter::Value;
+	/// use sciter::value::VALUE_STRING_CVT_TYPE;
+	///
+	/// let v = Value::parse_as("a: 1, b: 2", VALUE_STRING_CVT_TYPE::JSON_MAP).unwrap();
+	/// let s = v.to_string_as(VALUE_STRING_CVT_TYPE::JSON_MAP);
+	/// assert_eq!(Value::parse_as(&s, VALUE_STRING_CVT_TYPE::JSON_MAP), Ok(v));
+	/// ```
+	pub fn to_string_as(&self, how: VALUE_STRING_CVT_TYPE) -> String {
+		let mut copy = self.clone();
+		(_API.ValueToString)(copy.as_ptr(), how);
+		copy.as_string().unwrap_or_default()
+	}
+
+	/// Value as a standard JSON text, optionally pretty-printed.
+	///
+	/// Unlike [`into_string`](#method.into_string), which produces Sciter's JSON dialect,
+	/// this one is readable by any JSON parser. Sciter-specific types are stored as single-key objects
+	/// with a reserved key, so they can be restored by `From<serde_json::Value>` (the `json` feature):
+	///
+	/// | Sciter value | JSON |
+	/// |---|---|
+	/// | color | `{"$color": 4278190335}` (`0xAABBGGRR`) |
+	/// | date | `{"$date": "2017-07-14T02:40:00.1234567Z"}` (UTC) |
+	/// | currency | `{"$currency": "12.3400"}` |
+	/// | length | `{"$length": "12px"}` |
+	/// | range | `{"$range": [1, 10]}` |
+	/// | duration | `{"$duration": 0.5}` (seconds) |
+	/// | angle | `{"$angle": 1.5}` (radians) |
+	/// | bytes | `{"$bytes": "aGVsbG8="}` (base64) |
+	/// | symbol | `{"$symbol": "name"}` |
+	/// | error string | `{"$error": "message"}` |
+	/// | map with a single `$`-key | `{"$map": {"$color": 255}}` |
+	///
+	/// Non-string map keys are stored as their JSON text, floats keep a fractional part (`1.0`)
+	/// to stay floats, and non-finite floats become `null`.
+	/// `undefined` and values without data representation (functions, DOM elements, assets and so on)
+	/// are stored as `null`, they can't be restored.
+	///
+	/// A plain map whose only key starts with `$` is wrapped in `{"$map": ...}`,
+	/// so it is not mistaken for a typed value and comes back as the same map.
+	///
+	/// ```
+	/// # use sciter::Value;
+	/// let mut v = Value::map();
+	/// v.set_item("name", "Sciter");
+	/// v.set_item("color", Value::color(0xFF00_00FF));
+	/// assert_eq!(v.to_json_string(false), r#"{"name":"Sciter","color":{"$color":4278190335}}"#);
+	/// ```
+	pub fn to_json_string(&self, pretty: bool) -> String {
+		::json::to_string(self, pretty)
+	}
+
 	/// Value as a byte slice for `T_BYTES` type.
 	pub fn as_bytes(&self) -> Option<&[u8]> {
 		let mut s = 0 as LPCBYTE;
@@ -521,17 +1016,20 @@ impl Value {
 	///
 	/// The `name` here is an url or a name of the script - used for error reporting in script.
 	///
+	/// An error thrown by the function (or returned as an error value) is reported as [`ScriptError`](struct.ScriptError.html).
+	///
 	/// You can use the [`make_args!(args...)`](../macro.make_args.html) macro which helps you
 	/// to construct script arguments from Rust types.
-	pub fn call(&self, this: Option<Value>, args: &[Value], name: Option<&str>) -> Result<Value, VALUE_RESULT> {
+	pub fn call(&self, this: Option<Value>, args: &[Value], name: Option<&str>) -> Result<Value, ScriptError> {
 		let mut rv = Value::new();
 		let argv = Value::pack_args(args);
 		let name = s2w!(name.unwrap_or(""));
 		let ok = (_API.ValueInvoke)(self.as_cptr(), this.unwrap_or_default().as_ptr(),
 			argv.len() as UINT, argv.as_ptr(), rv.as_ptr(), name.as_ptr());
 		match ok {
-			VALUE_RESULT::OK => Ok(rv),
-			_ => Err(ok)
+			VALUE_RESULT::OK => ScriptError::check(rv),
+			_ if rv.is_verror() => Err(ScriptError::from_value(rv)),
+			_ => Err(ScriptError::failed(::Error::Value { api: "ValueInvoke", code: ok })),
 		}
 	}
 
@@ -641,6 +1139,14 @@ impl Value {
 		self.data.t as u32 == VALUE_TYPE::T_CURRENCY as u32
 	}
 	#[allow(missing_docs)]
+	pub const fn is_length(&self) -> bool {
+		self.data.t as u32 == VALUE_TYPE::T_LENGTH as u32
+	}
+	#[allow(missing_docs)]
+	pub const fn is_range(&self) -> bool {
+		self.data.t as u32 == VALUE_TYPE::T_RANGE as u32
+	}
+	#[allow(missing_docs)]
 	pub const fn is_color(&self) -> bool {
 		self.data.t as u32 == VALUE_TYPE::T_COLOR as u32
 	}
@@ -842,10 +1348,139 @@ impl Clone for Value {
 }
 
 /// Compare two values.
+///
+/// Values are equal if they have the same type and units and the same content:
+/// strings and bytes are compared by their data, arrays item by item, maps regardless of the order of their keys
+/// (which are matched by text, so a symbol key is the same as a string one).
+/// Floats are equal by value, except that all `NaN`s are equal to each other.
+/// References to script objects, functions and other handles are equal if they refer to the same object.
 impl ::std::cmp::PartialEq for Value {
 	fn eq(&self, other: &Self) -> bool {
-		let eq = (_API.ValueCompare)(self.as_cptr(), other.as_cptr());
-		matches!(eq, VALUE_RESULT::OK_TRUE)
+		self.cmp(other) == ::std::cmp::Ordering::Equal
+	}
+}
+
+impl ::std::cmp::Eq for Value {}
+
+impl ::std::cmp::PartialOrd for Value {
+	fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+/// Total order of values.
+///
+/// Values are ordered by their type (in the order of [`VALUE_TYPE`](enum.VALUE_TYPE.html)) and units first,
+/// then by their content; `NaN` is greater than any other float and maps are compared as arrays of
+/// key-value pairs sorted by the key text.
+impl ::std::cmp::Ord for Value {
+	fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+		if self.data.t == other.data.t && self.data.u == other.data.u && self.data.d == other.data.d {
+			return ::std::cmp::Ordering::Equal;
+		}
+		self.kind().cmp(&other.kind()).then_with(|| self.content().cmp(&other.content()))
+	}
+}
+
+/// Hash of the value content, consistent with the `Eq` implementation.
+impl ::std::hash::Hash for Value {
+	fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+		self.kind().hash(state);
+		self.content().hash(state);
+	}
+}
+
+impl Value {
+	/// Type and the significant units.
+	fn kind(&self) -> (u32, UINT) {
+		use capi::scvalue::VALUE_TYPE::*;
+		let units = match self.data.t {
+			T_ARRAY | T_MAP | T_BYTES => 0,
+			_ => self.data.u,
+		};
+		(self.data.t as u32, units)
+	}
+
+	/// The content to compare.
+	fn content(&self) -> Content {
+		use capi::scvalue::VALUE_TYPE::*;
+		let int64 = || {
+			let mut val = 0i64;
+			(_API.ValueInt64Data)(self.as_cptr(), &mut val);
+			val
+		};
+		match self.data.t {
+			T_UNDEFINED | T_NULL => Content::Empty,
+			T_BOOL | T_INT | T_COLOR => Content::Int(self.to_int().map_or(0, i64::from)),
+			T_DATE | T_CURRENCY => Content::Int(int64()),
+			T_RANGE => self.to_range().map_or(Content::Empty, |r| Content::Range(r.start, r.end)),
+			T_FLOAT | T_LENGTH | T_DURATION | T_ANGLE => Content::Float(Float(self.to_float().unwrap_or_default())),
+			T_STRING => Content::Text(self.as_string().unwrap_or_default()),
+			T_BYTES => Content::Bytes(self.to_bytes().unwrap_or_default()),
+			T_ARRAY => Content::Array(self.values().collect()),
+			T_MAP => {
+				let mut items: Vec<_> = self.iter_items().map(|(key, value)| (key_text(&key), value)).collect();
+				items.sort();
+				Content::Map(items)
+			},
+			_ => Content::Handle(self.data.d),
+		}
+	}
+}
+
+/// Comparable content of a value, see `Ord for Value`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Content {
+	Empty,
+	Int(i64),
+	Range(i32, i32),
+	Float(Float),
+	Text(String),
+	Bytes(Vec<u8>),
+	Array(Vec<Value>),
+	Map(Vec<(String, Value)>),
+	Handle(u64),
+}
+
+/// `f64` with total order: `-0.0` equals to `0.0`, `NaN`s are equal and greater than other numbers.
+#[derive(Clone, Copy)]
+struct Float(f64);
+
+impl Float {
+	fn bits(self) -> u64 {
+		if self.0.is_nan() {
+			f64::NAN.to_bits()
+		} else if self.0 == 0.0 {
+			0
+		} else {
+			self.0.to_bits()
+		}
+	}
+}
+
+impl ::std::cmp::PartialEq for Float {
+	fn eq(&self, other: &Self) -> bool {
+		self.cmp(other) == ::std::cmp::Ordering::Equal
+	}
+}
+
+impl ::std::cmp::Eq for Float {}
+
+impl ::std::cmp::PartialOrd for Float {
+	fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl ::std::cmp::Ord for Float {
+	fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+		self.0.partial_cmp(&other.0).unwrap_or_else(|| self.0.is_nan().cmp(&other.0.is_nan()))
+	}
+}
+
+impl ::std::hash::Hash for Float {
+	fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+		self.bits().hash(state);
 	}
 }
 
@@ -1019,18 +1654,30 @@ impl<'a> From<&'a [u8]> for Value {
 
 /// Value from time/date.
 impl From<std::time::SystemTime> for Value {
-	/// See the [VALUE_TIME::T_DATE].
+	/// `SystemTime` is always UTC, see [`Value::date`](struct.Value.html#method.date).
 	fn from(val: std::time::SystemTime) -> Self {
-		let mut me = Value::new();
-		if let Ok(epoch) = val.duration_since(std::time::UNIX_EPOCH) {
-			// in 100ns from epoch
-			let ns = epoch.as_nanos() / 100;
+		Value::date(val, true)
+	}
+}
 
-			// does not fail because does not check the input
-			// TODO: is SystemTime UTC or not?
-			(_API.ValueInt64DataSet)(me.as_ptr(), ns as i64, VALUE_TYPE::T_DATE as u32, 0);
-		}
-		me
+/// Value from currency.
+impl From<Currency> for Value {
+	fn from(val: Currency) -> Self {
+		Value::currency(val)
+	}
+}
+
+/// Value from length.
+impl From<Length> for Value {
+	fn from(val: Length) -> Self {
+		Value::length(val)
+	}
+}
+
+/// Value from range.
+impl From<Range> for Value {
+	fn from(val: Range) -> Self {
+		Value::range(val)
 	}
 }
 
@@ -1057,6 +1704,24 @@ impl<T, E> From<Result<T, E>> for Value where T: Into<Value>, E: std::fmt::Displ
 //   }
 // }
 
+/// Map value from sequence of key-value pairs.
+impl<K: Into<Value>, V: Into<Value>> ::std::iter::FromIterator<(K, V)> for Value {
+	fn from_iter<I: IntoIterator<Item=(K, V)>>(iterator: I) -> Self {
+		let mut v = Value::map();
+		v.extend(iterator);
+		return v;
+	}
+}
+
+/// Insert key-value pairs into the map, replacing the existing keys.
+impl<K: Into<Value>, V: Into<Value>> ::std::iter::Extend<(K, V)> for Value {
+	fn extend<I: IntoIterator<Item=(K, V)>>(&mut self, iterator: I) {
+		for (key, value) in iterator {
+			self.set_item(key, value);
+		}
+	}
+}
+
 /// Value from sequence of `Value`.
 impl ::std::iter::FromIterator<Value> for Value {
   fn from_iter<I: IntoIterator<Item=Value>>(iterator: I) -> Self {
@@ -1137,6 +1802,125 @@ where
 	}
 }
 
+/// A closure with typed parameters, see [`Value::from_fn`](struct.Value.html#method.from_fn).
+///
+/// Implemented for `Fn(A, B, ...) -> R` with up to 12 parameters
+/// where every parameter implements [`FromValue`](trait.FromValue.html) and `R: Into<Value>`.
+pub trait NativeFunction<Args> {
+	/// Convert the script arguments and call `self`.
+	///
+	/// Returns a [`Value::error`](struct.Value.html#method.error) if the arguments do not match the parameters.
+	fn call_native(&self, args: &[Value]) -> Value;
+}
+
+macro_rules! native_function_impls {
+	($($count:expr => ($($index:tt $arg:ident)*))+) => {
+		$(
+			impl<F, R, $($arg),*> NativeFunction<($($arg,)*)> for F
+			where
+				F: Fn($($arg),*) -> R,
+				R: Into<Value>,
+				$($arg: FromValue,)*
+			{
+				fn call_native(&self, _args: &[Value]) -> Value {
+					if _args.len() != $count {
+						return Value::error(&format!("native function error: {} of {} arguments provided.", _args.len(), $count));
+					}
+					let rv = self($(
+						match <$arg as FromValue>::try_from_value(&_args[$index]) {
+							Ok(arg) => arg,
+							Err(e) => {
+								return Value::error(&format!("native function error: invalid type of {} argument: {}.", $index, e));
+							},
+						}
+					),*);
+					rv.into()
+				}
+			}
+		)+
+	};
+}
+
+native_function_impls! {
+	0 => ()
+	1 => (0 A)
+	2 => (0 A 1 B)
+	3 => (0 A 1 B 2 C)
+	4 => (0 A 1 B 2 C 3 D)
+	5 => (0 A 1 B 2 C 3 D 4 E)
+	6 => (0 A 1 B 2 C 3 D 4 E 5 G)
+	7 => (0 A 1 B 2 C 3 D 4 E 5 G 6 H)
+	8 => (0 A 1 B 2 C 3 D 4 E 5 G 6 H 7 I)
+	9 => (0 A 1 B 2 C 3 D 4 E 5 G 6 H 7 I 8 J)
+	10 => (0 A 1 B 2 C 3 D 4 E 5 G 6 H 7 I 8 J 9 K)
+	11 => (0 A 1 B 2 C 3 D 4 E 5 G 6 H 7 I 8 J 9 K 10 L)
+	12 => (0 A 1 B 2 C 3 D 4 E 5 G 6 H 7 I 8 J 9 K 10 L 11 M)
+}
+
+/// Arguments of a typed script call, see [`Host::call`](../host/struct.Host.html#method.call)
+/// and [`Element::call`](../dom/struct.Element.html#method.call).
+///
+/// Implemented for tuples with up to 12 elements where every element implements `Into<Value>`
+/// (so `()` means no arguments and `(x,)` is a single one), and for arrays, vectors and slices of values.
+///
+/// ```
+/// use sciter::Value;
+/// use sciter::value::IntoArgs;
+///
+/// assert_eq!((1, "x", 2.0).into_args(), vec![Value::from(1), Value::from("x"), Value::from(2.0)]);
+/// assert!(().into_args().is_empty());
+/// ```
+pub trait IntoArgs {
+	/// Convert `self` into the argument list.
+	fn into_args(self) -> Vec<Value>;
+}
+
+macro_rules! into_args_impls {
+	($(($($index:tt $arg:ident)*))+) => {
+		$(
+			impl<$($arg: Into<Value>),*> IntoArgs for ($($arg,)*) {
+				fn into_args(self) -> Vec<Value> {
+					vec![$(self.$index.into()),*]
+				}
+			}
+		)+
+	};
+}
+
+into_args_impls! {
+	()
+	(0 A)
+	(0 A 1 B)
+	(0 A 1 B 2 C)
+	(0 A 1 B 2 C 3 D)
+	(0 A 1 B 2 C 3 D 4 E)
+	(0 A 1 B 2 C 3 D 4 E 5 G)
+	(0 A 1 B 2 C 3 D 4 E 5 G 6 H)
+	(0 A 1 B 2 C 3 D 4 E 5 G 6 H 7 I)
+	(0 A 1 B 2 C 3 D 4 E 5 G 6 H 7 I 8 J)
+	(0 A 1 B 2 C 3 D 4 E 5 G 6 H 7 I 8 J 9 K)
+	(0 A 1 B 2 C 3 D 4 E 5 G 6 H 7 I 8 J 9 K 10 L)
+	(0 A 1 B 2 C 3 D 4 E 5 G 6 H 7 I 8 J 9 K 10 L 11 M)
+}
+
+impl<const N: usize> IntoArgs for [Value; N] {
+	fn into_args(self) -> Vec<Value> {
+		self.into()
+	}
+}
+
+impl IntoArgs for Vec<Value> {
+	fn into_args(self) -> Vec<Value> {
+		self
+	}
+}
+
+impl IntoArgs for &[Value] {
+	fn into_args(self) -> Vec<Value> {
+		self.to_vec()
+	}
+}
+
 /// Value from asset.
 impl<T> From<Box<IAsset<T>>> for Value {
 	fn from(ptr: Box<IAsset<T>>) -> Value {
@@ -1172,21 +1956,842 @@ where
 }
 
 
-/// Helper trait
-pub trait FromValue {
-	/// Converts value to specified type.
-	fn from_value(v: &Value) -> Option<Self> where Self: Sized;
+/// Map keys equality, where a symbol matches a string with the same text.
+fn same_key(a: &Value, b: &Value) -> bool {
+	if a.is_string() && b.is_string() {
+		a.as_string() == b.as_string()
+	} else {
+		a == b
+	}
 }
 
-impl FromValue for Value {
-	fn from_value(v: &Value) -> Option<Self> {
-		Some(v.clone())
-	}
+
+/// A view into a single map entry, see [`Value::entry`](struct.Value.html#method.entry).
+pub enum Entry<'a> {
+	/// The key is present.
+	Occupied(OccupiedEntry<'a>),
+	/// The key is absent.
+	Vacant(VacantEntry<'a>),
 }
 
-impl FromValue for bool {
-	fn from_value(v: &Value) -> Option<Self> {
-		v.to_bool()
+/// A view into a present map entry.
+pub struct OccupiedEntry<'a> {
+	map: &'a mut Value,
+	key: Value,
+	value: Value,
+}
+
+/// A view into an absent map entry.
+pub struct VacantEntry<'a> {
+	map: &'a mut Value,
+	key: Value,
+}
+
+impl<'a> Entry<'a> {
+	/// The key of this entry.
+	pub fn key(&self) -> &Value {
+		match *self {
+			Entry::Occupied(ref e) => e.key(),
+			Entry::Vacant(ref e) => e.key(),
+		}
+	}
+
+	/// Insert `default` if the entry is vacant. Returns a reference to the entry's value.
+	pub fn or_insert<T: Into<Value>>(self, default: T) -> EntryRef<'a> {
+		match self {
+			Entry::Occupied(e) => e.into_mut(),
+			Entry::Vacant(e) => e.insert(default),
+		}
+	}
+
+	/// Insert the result of `default` if the entry is vacant. Returns a reference to the entry's value.
+	pub fn or_insert_with<T: Into<Value>, F: FnOnce() -> T>(self, default: F) -> EntryRef<'a> {
+		match self {
+			Entry::Occupied(e) => e.into_mut(),
+			Entry::Vacant(e) => e.insert(default()),
+		}
+	}
+
+	/// Insert an undefined value if the entry is vacant. Returns a reference to the entry's value.
+	pub fn or_default(self) -> EntryRef<'a> {
+		self.or_insert_with(Value::new)
+	}
+
+	/// Modify the present value in place and store it back to the map.
+	pub fn and_modify<F: FnOnce(&mut Value)>(self, f: F) -> Self {
+		match self {
+			Entry::Occupied(mut e) => {
+				f(&mut e.value);
+				e.map.set_item(e.key.clone(), e.value.clone());
+				Entry::Occupied(e)
+			},
+			vacant => vacant,
+		}
+	}
+}
+
+impl<'a> OccupiedEntry<'a> {
+	/// The key of this entry.
+	pub fn key(&self) -> &Value {
+		&self.key
+	}
+
+	/// The value of this entry.
+	pub fn get(&self) -> &Value {
+		&self.value
+	}
+
+	/// Converts the entry into a reference to its value.
+	pub fn into_mut(self) -> EntryRef<'a> {
+		EntryRef { map: self.map, key: self.key, value: self.value, dirty: false }
+	}
+
+	/// Replace the value of this entry, returning the old one.
+	pub fn insert<T: Into<Value>>(&mut self, value: T) -> Value {
+		let value = value.into();
+		self.map.set_item(self.key.clone(), value.clone());
+		::std::mem::replace(&mut self.value, value)
+	}
+}
+
+impl<'a> VacantEntry<'a> {
+	/// The key of this entry.
+	pub fn key(&self) -> &Value {
+		&self.key
+	}
+
+	/// Insert the value into the map. Returns a reference to the inserted value.
+	pub fn insert<T: Into<Value>>(self, value: T) -> EntryRef<'a> {
+		let value = value.into();
+		self.map.set_item(self.key.clone(), value.clone());
+		EntryRef { map: self.map, key: self.key, value, dirty: false }
+	}
+}
+
+/// A mutable reference to a map value, returned by [`Entry::or_insert`](enum.Entry.html#method.or_insert).
+///
+/// Dereferences to a copy of the value and stores it back to the map when dropped,
+/// if it was mutably dereferenced.
+pub struct EntryRef<'a> {
+	map: &'a mut Value,
+	key: Value,
+	value: Value,
+	dirty: bool,
+}
+
+impl<'a> ::std::ops::Deref for EntryRef<'a> {
+	type Target = Value;
+
+	fn deref(&self) -> &Value {
+		&self.value
+	}
+}
+
+impl<'a> ::std::ops::DerefMut for EntryRef<'a> {
+	fn deref_mut(&mut self) -> &mut Value {
+		self.dirty = true;
+		&mut self.value
+	}
+}
+
+impl<'a> Drop for EntryRef<'a> {
+	fn drop(&mut self) {
+		if self.dirty {
+			self.map.set_item(self.key.clone(), self.value.clone());
+		}
+	}
+}
+
+
+/// A mutable reference to a nested value, returned by [`Value::pointer_mut`](struct.Value.html#method.pointer_mut)
+/// and [`Value::get_path_mut`](struct.Value.html#method.get_path_mut).
+///
+/// Dereferences to a copy of the value and stores it back by the same path when dropped,
+/// if it was mutably dereferenced.
+pub struct PathRef<'a> {
+	root: &'a mut Value,
+	steps: Vec<PathStep>,
+	value: Value,
+	dirty: bool,
+}
+
+impl<'a> ::std::ops::Deref for PathRef<'a> {
+	type Target = Value;
+
+	fn deref(&self) -> &Value {
+		&self.value
+	}
+}
+
+impl<'a> ::std::ops::DerefMut for PathRef<'a> {
+	fn deref_mut(&mut self) -> &mut Value {
+		self.dirty = true;
+		&mut self.value
+	}
+}
+
+impl<'a> Drop for PathRef<'a> {
+	fn drop(&mut self) {
+		if self.dirty {
+			// the path was resolved on lookup and `root` is borrowed since then
+			let _ = self.root.assign_path(&self.steps, self.value.clone());
+		}
+	}
+}
+
+
+/// A segment of a nested value path.
+#[derive(Debug)]
+enum PathStep {
+	/// Map key.
+	Key(String),
+	/// Array index, or a numeric map key.
+	Index(usize),
+	/// Past the end of an array (`-` in JSON pointers).
+	Append,
+}
+
+impl PathStep {
+	fn from_segment(segment: String) -> PathStep {
+		match segment.parse() {
+			Ok(index) if segment.bytes().all(|c| c.is_ascii_digit()) => PathStep::Index(index),
+			_ => PathStep::Key(segment),
+		}
+	}
+
+	/// `/a/b/2/c`, the empty pointer refers to the whole value.
+	fn parse_pointer(pointer: &str) -> Option<Vec<PathStep>> {
+		if pointer.is_empty() {
+			return Some(Vec::new());
+		}
+		let steps = pointer.strip_prefix('/')?.split('/').map(|segment| {
+			if segment == "-" {
+				PathStep::Append
+			} else {
+				PathStep::from_segment(segment.replace("~1", "/").replace("~0", "~"))
+			}
+		});
+		Some(steps.collect())
+	}
+
+	/// `a.b[2].c` or `a.b.2.c`, the empty path refers to the whole value.
+	fn parse_dotted(path: &str) -> Option<Vec<PathStep>> {
+		let mut steps = Vec::new();
+		if path.is_empty() {
+			return Some(steps);
+		}
+		for (n, part) in path.split('.').enumerate() {
+			let (key, mut indices) = match part.find('[') {
+				Some(pos) => (&part[..pos], &part[pos..]),
+				None => (part, ""),
+			};
+			// only the first part may start with an index, like `[1].name`
+			if !key.is_empty() {
+				steps.push(PathStep::from_segment(key.to_string()));
+			} else if n > 0 || indices.is_empty() {
+				return None;
+			}
+			while !indices.is_empty() {
+				let end = indices.find(']')?;
+				let index = indices.get(1..end)?;
+				if index.is_empty() || !index.bytes().all(|c| c.is_ascii_digit()) {
+					return None;
+				}
+				steps.push(PathStep::Index(index.parse().ok()?));
+				indices = &indices[end + 1..];
+				if !indices.is_empty() && !indices.starts_with('[') {
+					return None;
+				}
+			}
+		}
+		Some(steps)
+	}
+}
+
+
+/// A single change reported by [`Value::diff`](struct.Value.html#method.diff).
+///
+/// The path is a JSON pointer to the changed item.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Change {
+	/// The item exists only in the new value.
+	Added { path: String, value: Value },
+	/// The item exists only in the old value.
+	Removed { path: String, value: Value },
+	/// The item differs.
+	Changed { path: String, old: Value, new: Value },
+}
+
+impl Change {
+	/// JSON pointer to the changed item.
+	pub fn path(&self) -> &str {
+		match self {
+			Change::Added { path, .. } | Change::Removed { path, .. } | Change::Changed { path, .. } => path,
+		}
+	}
+}
+
+/// Map key as a path segment.
+fn key_text(key: &Value) -> String {
+	key.as_string().unwrap_or_else(|| key.to_string())
+}
+
+
+/// Difference between the `FILETIME` epoch (1601-01-01) and the unix one, in 100ns intervals.
+const FILETIME_UNIX_EPOCH: i64 = 116_444_736_000_000_000;
+
+/// `SystemTime` to `FILETIME` ticks, saturated to the `i64` range.
+fn filetime_from(val: ::std::time::SystemTime) -> i64 {
+	use std::convert::TryFrom;
+	match val.duration_since(::std::time::UNIX_EPOCH) {
+		Ok(since) => i64::try_from(since.as_nanos() / 100).ok()
+			.and_then(|ticks| FILETIME_UNIX_EPOCH.checked_add(ticks))
+			.unwrap_or(i64::MAX),
+		Err(before) => i64::try_from(before.duration().as_nanos() / 100).ok()
+			.and_then(|ticks| FILETIME_UNIX_EPOCH.checked_sub(ticks))
+			.unwrap_or(i64::MIN),
+	}
+}
+
+/// `FILETIME` ticks to `SystemTime`, `None` if it is out of the platform range.
+fn filetime_to(ticks: i64) -> Option<::std::time::SystemTime> {
+	use std::time::{Duration, UNIX_EPOCH};
+	const TICKS_PER_SECOND: u64 = 10_000_000;
+	let since = i128::from(ticks) - i128::from(FILETIME_UNIX_EPOCH);
+	let abs = since.unsigned_abs() as u64;
+	let interval = Duration::new(abs / TICKS_PER_SECOND, (abs % TICKS_PER_SECOND) as u32 * 100);
+	if since >= 0 {
+		UNIX_EPOCH.checked_add(interval)
+	} else {
+		UNIX_EPOCH.checked_sub(interval)
+	}
+}
+
+
+/// Fixed-point currency number as stored by Sciter: a 64-bit integer scaled by 10000.
+///
+/// ```
+/// use sciter::value::{Currency, Value};
+///
+/// let price: Currency = "12.34".parse().unwrap();
+/// assert_eq!(price.raw(), 123400);
+/// assert_eq!(price.to_string(), "12.3400");
+///
+/// let v = Value::from(price);
+/// assert!(v.is_currency());
+/// assert_eq!(v.to_currency(), Some(price));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Currency(i64);
+
+impl Currency {
+	/// Number of raw units in one currency unit.
+	pub const SCALE: i64 = 10_000;
+
+	/// Make a currency from its raw representation (value multiplied by [`SCALE`](#associatedconstant.SCALE)).
+	pub const fn from_raw(raw: i64) -> Currency {
+		Currency(raw)
+	}
+
+	/// Make a currency from whole units.
+	pub const fn from_units(units: i64) -> Currency {
+		Currency(units * Currency::SCALE)
+	}
+
+	/// Make a currency from a float, rounding to the nearest raw unit.
+	pub fn from_f64(val: f64) -> Currency {
+		Currency((val * Currency::SCALE as f64).round() as i64)
+	}
+
+	/// Raw representation (value multiplied by [`SCALE`](#associatedconstant.SCALE)).
+	pub const fn raw(self) -> i64 {
+		self.0
+	}
+
+	/// Convert to float (possibly losing precision).
+	pub fn to_f64(self) -> f64 {
+		self.0 as f64 / Currency::SCALE as f64
+	}
+}
+
+impl ::std::fmt::Display for Currency {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+		let sign = if self.0 < 0 { "-" } else { "" };
+		let abs = self.0.unsigned_abs();
+		let scale = Currency::SCALE as u64;
+		write!(f, "{}{}.{:04}", sign, abs / scale, abs % scale)
+	}
+}
+
+/// Parses a decimal number with at most 4 fractional digits, e.g. `-12.34`.
+impl ::std::str::FromStr for Currency {
+	type Err = ::std::num::ParseIntError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (whole, fraction) = match s.find('.') {
+			Some(dot) => (&s[..dot], &s[dot + 1..]),
+			None => (s, ""),
+		};
+		let no_digits = whole.trim_start_matches(['+', '-']).is_empty() && fraction.is_empty();
+		if fraction.len() > 4 || no_digits {
+			// not a fixed-point number, let the integer parser describe the error
+			return s.parse().map(Currency);
+		}
+		// `12.34` is `123400` raw units
+		format!("{}{:0<4}", whole, fraction).parse().map(Currency)
+	}
+}
+
+
+/// CSS length with its units.
+///
+/// ```
+/// use sciter::value::{Length, Value};
+///
+/// let width = Length::Px(12.0);
+/// assert_eq!(width.to_string(), "12px");
+///
+/// let v = Value::from(width);
+/// assert!(v.is_length());
+/// assert_eq!(v.to_length(), Some(width));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Length {
+	/// Height of the element's font, `em`.
+	Em(f64),
+	/// Height of letter 'x', `ex`.
+	Ex(f64),
+	/// Percents, `%`.
+	Percent(f64),
+	/// Flex units (a.k.a. "springs"), `*` or `fx`.
+	Flex(f64),
+	/// Pixels, `px`.
+	Px(f64),
+	/// Inches, `in`.
+	In(f64),
+	/// Centimeters, `cm`.
+	Cm(f64),
+	/// Millimeters, `mm`.
+	Mm(f64),
+	/// Points (1/72 inches), `pt`.
+	Pt(f64),
+	/// Picas (12 points), `pc`.
+	Pc(f64),
+	/// Device independent pixels, `dip`.
+	Dip(f64),
+}
+
+impl Length {
+	/// Make a length from the number and its units.
+	///
+	/// Returns `None` for [`URL`](enum.VALUE_UNIT_TYPE_LENGTH.html#variant.URL) which is not a length.
+	pub fn new(value: f64, unit: VALUE_UNIT_TYPE_LENGTH) -> Option<Length> {
+		Length::from_raw(value, unit as UINT)
+	}
+
+	/// Make a length from the number and the raw `T_LENGTH` units.
+	fn from_raw(value: f64, unit: UINT) -> Option<Length> {
+		use self::VALUE_UNIT_TYPE_LENGTH as U;
+		let make = match unit {
+			u if u == U::EM as UINT => Length::Em,
+			u if u == U::EX as UINT => Length::Ex,
+			u if u == U::PR as UINT => Length::Percent,
+			u if u == U::SP as UINT => Length::Flex,
+			u if u == U::PX as UINT => Length::Px,
+			u if u == U::IN as UINT => Length::In,
+			u if u == U::CM as UINT => Length::Cm,
+			u if u == U::MM as UINT => Length::Mm,
+			u if u == U::PT as UINT => Length::Pt,
+			u if u == U::PC as UINT => Length::Pc,
+			u if u == U::DIP as UINT => Length::Dip,
+			_ => return None,
+		};
+		Some(make(value))
+	}
+
+	/// The number without units.
+	pub fn value(&self) -> f64 {
+		match *self {
+			Length::Em(v) | Length::Ex(v) | Length::Percent(v) | Length::Flex(v) | Length::Px(v) | Length::In(v)
+			| Length::Cm(v) | Length::Mm(v) | Length::Pt(v) | Length::Pc(v) | Length::Dip(v) => v,
+		}
+	}
+
+	/// Length units.
+	pub fn unit(&self) -> VALUE_UNIT_TYPE_LENGTH {
+		use self::VALUE_UNIT_TYPE_LENGTH as U;
+		match *self {
+			Length::Em(_) => U::EM,
+			Length::Ex(_) => U::EX,
+			Length::Percent(_) => U::PR,
+			Length::Flex(_) => U::SP,
+			Length::Px(_) => U::PX,
+			Length::In(_) => U::IN,
+			Length::Cm(_) => U::CM,
+			Length::Mm(_) => U::MM,
+			Length::Pt(_) => U::PT,
+			Length::Pc(_) => U::PC,
+			Length::Dip(_) => U::DIP,
+		}
+	}
+
+	/// CSS suffix of the units.
+	pub fn suffix(&self) -> &'static str {
+		match *self {
+			Length::Em(_) => "em",
+			Length::Ex(_) => "ex",
+			Length::Percent(_) => "%",
+			Length::Flex(_) => "*",
+			Length::Px(_) => "px",
+			Length::In(_) => "in",
+			Length::Cm(_) => "cm",
+			Length::Mm(_) => "mm",
+			Length::Pt(_) => "pt",
+			Length::Pc(_) => "pc",
+			Length::Dip(_) => "dip",
+		}
+	}
+}
+
+/// CSS text of the length, e.g. `12px` or `50%`.
+impl ::std::fmt::Display for Length {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+		write!(f, "{}{}", self.value(), self.suffix())
+	}
+}
+
+/// Parses CSS text of the length, e.g. `12px`, `1.5em`, `50%` or `1*`.
+///
+/// ```
+/// use sciter::value::Length;
+///
+/// assert_eq!("12px".parse(), Ok(Length::Px(12.0)));
+/// assert!("12".parse::<Length>().is_err());
+/// ```
+impl ::std::str::FromStr for Length {
+	type Err = ParseLengthError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let error = || ParseLengthError(s.to_string());
+		let text = s.trim();
+		let at = text.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+')).ok_or_else(error)?;
+		let (number, suffix) = text.split_at(at);
+		let number: f64 = number.parse().map_err(|_| error())?;
+		let units: [fn(f64) -> Length; 11] = [
+			Length::Em, Length::Ex, Length::Percent, Length::Flex, Length::Px, Length::In,
+			Length::Cm, Length::Mm, Length::Pt, Length::Pc, Length::Dip,
+		];
+		units.iter().map(|unit| unit(number)).find(|length| length.suffix() == suffix.trim()).ok_or_else(error)
+	}
+}
+
+/// An error returned when parsing a [`Length`](enum.Length.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLengthError(String);
+
+impl ::std::fmt::Display for ParseLengthError {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+		write!(f, "invalid length {:?}", self.0)
+	}
+}
+
+impl ::std::error::Error for ParseLengthError {}
+
+
+/// Sciter `N..M` integer range.
+///
+/// ```
+/// use sciter::value::{Range, Value};
+///
+/// let v = Value::from(Range::new(1, 10));
+/// assert!(v.is_range());
+/// assert_eq!(v.to_range(), Some(Range::from(1..10)));
+/// assert_eq!(v.to_range().unwrap().to_string(), "1..10");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Range {
+	/// Lower bound.
+	pub start: i32,
+	/// Upper bound.
+	pub end: i32,
+}
+
+impl Range {
+	/// Make a `start..end` range.
+	pub const fn new(start: i32, end: i32) -> Range {
+		Range { start, end }
+	}
+}
+
+impl From<::std::ops::Range<i32>> for Range {
+	fn from(r: ::std::ops::Range<i32>) -> Self {
+		Range::new(r.start, r.end)
+	}
+}
+
+impl From<Range> for ::std::ops::Range<i32> {
+	fn from(r: Range) -> Self {
+		r.start..r.end
+	}
+}
+
+/// Sciter text of the range, e.g. `1..10`.
+impl ::std::fmt::Display for Range {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+		write!(f, "{}..{}", self.start, self.end)
+	}
+}
+
+
+/// Helper trait
+pub trait FromValue {
+	/// Converts value to specified type.
+	fn from_value(v: &Value) -> Option<Self> where Self: Sized;
+
+	/// Converts value to specified type, describing the failed element on error.
+	///
+	/// The default implementation calls [`from_value`](#tymethod.from_value)
+	/// and reports the Rust type name as the expected type.
+	fn try_from_value(v: &Value) -> Result<Self, ConversionError> where Self: Sized {
+		Self::from_value(v).ok_or_else(|| ConversionError::new(::std::any::type_name::<Self>(), v))
+	}
+}
+
+/// An error returned by [`FromValue::try_from_value`](trait.FromValue.html#method.try_from_value).
+///
+/// Holds the path to the offending element (e.g. `[2].name`), the expected type and the given value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversionError {
+	path: Vec<PathItem>,
+	expected: String,
+	given: String,
+}
+
+/// A segment of the [`ConversionError`](struct.ConversionError.html) path.
+#[derive(Debug, Clone, PartialEq)]
+enum PathItem {
+	Index(usize),
+	Key(String),
+}
+
+impl ConversionError {
+	/// Create a new error for the `given` value that was expected to be of `expected` type.
+	pub fn new(expected: &str, given: &Value) -> Self {
+		ConversionError {
+			path: Vec::new(),
+			expected: expected.to_owned(),
+			given: format!("{:?}", given),
+		}
+	}
+
+	/// Mark the error as occurred at the `index` element of an array.
+	pub fn at_index(mut self, index: usize) -> Self {
+		self.path.push(PathItem::Index(index));
+		self
+	}
+
+	/// Mark the error as occurred at the `key` item of a map.
+	pub fn at_key(mut self, key: &str) -> Self {
+		self.path.push(PathItem::Key(key.to_owned()));
+		self
+	}
+
+	/// The path to the offending element, e.g. `[2].name` or an empty string for the value itself.
+	pub fn path(&self) -> String {
+		let mut s = String::new();
+		for item in self.path.iter().rev() {
+			match item {
+				PathItem::Index(index) => s.push_str(&format!("[{}]", index)),
+				PathItem::Key(key) => {
+					if !s.is_empty() {
+						s.push('.');
+					}
+					s.push_str(key);
+				}
+			}
+		}
+		s
+	}
+
+	/// The expected type name.
+	pub fn expected(&self) -> &str {
+		&self.expected
+	}
+}
+
+impl ::std::fmt::Display for ConversionError {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+		let path = self.path();
+		if !path.is_empty() {
+			write!(f, "{}: ", path)?;
+		}
+		write!(f, "expected {}, given {}", self.expected, self.given)
+	}
+}
+
+impl ::std::error::Error for ConversionError {}
+
+
+/// An error thrown by script.
+///
+/// Returned by [`Value::call`](struct.Value.html#method.call), [`Host::eval_script`](../host/struct.Host.html#method.eval_script),
+/// [`Element::call_function`](../dom/struct.Element.html#method.call_function) and other script-invoking functions
+/// instead of the raw error value. The source location and stack are filled when the engine provides them.
+/// A call that fails without throwing (e.g. on an invalid element) carries the failed function
+/// and its result code instead, see [`api_error`](#method.api_error);
+/// such an error is displayed as "script call failed" with the failure as its `source`.
+///
+/// ```
+/// use sciter::Value;
+/// use sciter::value::ScriptError;
+///
+/// let thrown: Value = "{message: 'oops', fileName: 'main.js', lineNumber: 12}".parse().unwrap();
+/// let e = ScriptError::from_value(thrown);
+/// assert_eq!(e.message(), "oops");
+/// assert_eq!(e.line(), Some(12));
+/// assert_eq!(e.to_string(), "oops at main.js:12");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptError {
+	message: String,
+	url: Option<String>,
+	line: Option<u32>,
+	column: Option<u32>,
+	stack: Option<String>,
+	value: Value,
+	failure: Option<Box<::Error>>,
+}
+
+impl ScriptError {
+	/// Create an error with the given message, e.g. for a failed call that has no error value.
+	pub fn new(message: &str) -> Self {
+		ScriptError {
+			message: message.to_owned(),
+			url: None,
+			line: None,
+			column: None,
+			stack: None,
+			value: Value::error(message),
+			failure: None,
+		}
+	}
+
+	/// Create an error for a failed Sciter call which has thrown nothing, see [`api_error`](#method.api_error).
+	pub(crate) fn failed(error: ::Error) -> Self {
+		let mut me = ScriptError::new(&error.to_string());
+		me.failure = Some(Box::new(error));
+		me
+	}
+
+	/// Create an error from the thrown value.
+	///
+	/// Error objects (and maps) provide the `message`, `fileName`, `lineNumber`, `columnNumber` and `stack` properties,
+	/// error strings and any other values are taken as the message.
+	pub fn from_value(value: Value) -> Self {
+		if !value.is_vmap() && !value.is_object_error() {
+			let mut error = ScriptError::new(&value.as_string().unwrap_or_else(|| value.to_string()));
+			error.value = value;
+			return error;
+		}
+		let text = |keys: &[&str]| keys.iter()
+			.map(|&key| value.get_item(key))
+			.find(|item| !item.is_undefined() && !item.is_null())
+			.map(|item| item.as_string().unwrap_or_else(|| item.to_string()));
+		let number = |keys: &[&str]| text(keys).and_then(|s| s.parse().ok());
+		ScriptError {
+			message: text(&["message"]).unwrap_or_else(|| value.to_string()),
+			url: text(&["fileName", "url"]),
+			line: number(&["lineNumber", "line"]),
+			column: number(&["columnNumber", "column"]),
+			stack: text(&["stack"]),
+			value: value.clone(),
+			failure: None,
+		}
+	}
+
+	/// Turn the returned error value into `Err`.
+	pub(crate) fn check(rv: Value) -> Result<Value, ScriptError> {
+		if rv.is_verror() {
+			Err(ScriptError::from_value(rv))
+		} else {
+			Ok(rv)
+		}
+	}
+
+	/// The error message.
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+
+	/// Url of the script where the error occurred.
+	pub fn url(&self) -> Option<&str> {
+		self.url.as_deref()
+	}
+
+	/// Line number where the error occurred.
+	pub fn line(&self) -> Option<u32> {
+		self.line
+	}
+
+	/// Column number where the error occurred.
+	pub fn column(&self) -> Option<u32> {
+		self.column
+	}
+
+	/// Script stack trace.
+	pub fn stack(&self) -> Option<&str> {
+		self.stack.as_deref()
+	}
+
+	/// The thrown value itself.
+	pub fn value(&self) -> &Value {
+		&self.value
+	}
+
+	/// The failed Sciter function and its result code if the script has not thrown anything,
+	/// e.g. [`Error::Dom`](../error/enum.Error.html#variant.Dom) for an invalid element.
+	///
+	/// Such errors are converted to this one instead of [`Error::Script`](../error/enum.Error.html#variant.Script).
+	pub fn api_error(&self) -> Option<&::Error> {
+		self.failure.as_deref()
+	}
+}
+
+impl ::std::fmt::Display for ScriptError {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+		if self.failure.is_some() {
+			// the failure itself is the `source`
+			return f.write_str("script call failed");
+		}
+		f.write_str(&self.message)?;
+		if let Some(ref url) = self.url {
+			write!(f, " at {}", url)?;
+			if let Some(line) = self.line {
+				write!(f, ":{}", line)?;
+				if let Some(column) = self.column {
+					write!(f, ":{}", column)?;
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+impl ::std::error::Error for ScriptError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		self.failure.as_ref().map(|e| e.as_ref() as &(dyn std::error::Error + 'static))
+	}
+}
+
+impl FromValue for Value {
+	fn from_value(v: &Value) -> Option<Self> {
+		Some(v.clone())
+	}
+}
+
+impl FromValue for bool {
+	fn from_value(v: &Value) -> Option<Self> {
+		v.to_bool()
 	}
 }
 
@@ -1202,9 +2807,49 @@ impl FromValue for f64 {
 	}
 }
 
-impl FromValue for Vec<u8> {
+impl FromValue for f32 {
+	fn from_value(v: &Value) -> Option<Self> {
+		v.to_float().map(|f| f as f32)
+	}
+}
+
+impl FromValue for ::std::time::SystemTime {
+	fn from_value(v: &Value) -> Option<Self> {
+		v.to_date()
+	}
+
+	fn try_from_value(v: &Value) -> Result<Self, ConversionError> {
+		v.to_date().ok_or_else(|| ConversionError::new("date", v))
+	}
+}
+
+impl FromValue for Currency {
 	fn from_value(v: &Value) -> Option<Self> {
-		v.to_bytes()
+		v.to_currency()
+	}
+
+	fn try_from_value(v: &Value) -> Result<Self, ConversionError> {
+		v.to_currency().ok_or_else(|| ConversionError::new("currency", v))
+	}
+}
+
+impl FromValue for Length {
+	fn from_value(v: &Value) -> Option<Self> {
+		v.to_length()
+	}
+
+	fn try_from_value(v: &Value) -> Result<Self, ConversionError> {
+		v.to_length().ok_or_else(|| ConversionError::new("length", v))
+	}
+}
+
+impl FromValue for Range {
+	fn from_value(v: &Value) -> Option<Self> {
+		v.to_range()
+	}
+
+	fn try_from_value(v: &Value) -> Result<Self, ConversionError> {
+		v.to_range().ok_or_else(|| ConversionError::new("range", v))
 	}
 }
 
@@ -1212,6 +2857,450 @@ impl FromValue for String {
 	fn from_value(v: &Value) -> Option<Self> {
 		v.as_string()
 	}
+
+	fn try_from_value(v: &Value) -> Result<Self, ConversionError> {
+		v.as_string().ok_or_else(|| ConversionError::new("string", v))
+	}
+}
+
+/// Integer value, or a float value without a fractional part (used by Sciter for large numbers).
+fn integer_of(v: &Value) -> Option<i64> {
+	if let Some(i) = v.to_int() {
+		return Some(i64::from(i));
+	}
+	const MAX_SAFE: f64 = 9_007_199_254_740_992.0; // 2^53
+	match v.to_float() {
+		Some(f) if v.is_float() && f.fract() == 0.0 && f.abs() <= MAX_SAFE => Some(f as i64),
+		_ => None,
+	}
+}
+
+// Integers with range checks.
+//
+// Note that there are no generic 64-bit integers at Sciter,
+// so integers that do not fit in `i32` are stored as `f64`.
+macro_rules! integer_value_impls {
+	($($t:ident)*) => {
+		$(
+			impl FromValue for $t {
+				fn from_value(v: &Value) -> Option<Self> {
+					Self::try_from_value(v).ok()
+				}
+
+				fn try_from_value(v: &Value) -> Result<Self, ConversionError> {
+					integer_of(v)
+						.and_then(|i| <$t as ::std::convert::TryFrom<i64>>::try_from(i).ok())
+						.ok_or_else(|| ConversionError::new(stringify!($t), v))
+				}
+			}
+
+			/// Value from integer, stored as `f64` if it does not fit in `i32`.
+			///
+			/// Note that `f64` holds integers exactly only up to 2<sup>53</sup>,
+			/// larger `i64` values are rounded to the nearest representable one.
+			impl From<$t> for Value {
+				fn from(val: $t) -> Self {
+					match <i32 as ::std::convert::TryFrom<$t>>::try_from(val) {
+						Ok(i) => Value::from(i),
+						Err(_) => Value::from(val as f64),
+					}
+				}
+			}
+		)*
+	};
+}
+
+integer_value_impls!(u8 u16 u32 i8 i16 i64);
+
+/// Value from float.
+impl From<f32> for Value {
+	fn from(val: f32) -> Self {
+		Value::from(f64::from(val))
+	}
+}
+
+/// Array or bytes to vector.
+///
+/// Note that `Vec<u8>` accepts both `T_BYTES` and an array of integers.
+impl<T: FromValue> FromValue for Vec<T> {
+	fn from_value(v: &Value) -> Option<Self> {
+		Self::try_from_value(v).ok()
+	}
+
+	fn try_from_value(v: &Value) -> Result<Self, ConversionError> {
+		if let Some(bytes) = v.as_bytes() {
+			return bytes.iter().enumerate()
+				.map(|(i, &b)| T::try_from_value(&Value::from(i32::from(b))).map_err(|e| e.at_index(i)))
+				.collect();
+		}
+		if !(v.is_array() || v.is_object_array()) {
+			return Err(ConversionError::new("array", v));
+		}
+		v.values().enumerate()
+			.map(|(i, item)| T::try_from_value(&item).map_err(|e| e.at_index(i)))
+			.collect()
+	}
+}
+
+impl<T: FromValue, const N: usize> FromValue for [T; N] {
+	fn from_value(v: &Value) -> Option<Self> {
+		Self::try_from_value(v).ok()
+	}
+
+	fn try_from_value(v: &Value) -> Result<Self, ConversionError> {
+		let items: Vec<T> = Vec::try_from_value(v)?;
+		let expected = || ConversionError::new(&format!("array of {} elements", N), v);
+		if items.len() != N {
+			return Err(expected());
+		}
+		<[T; N] as ::std::convert::TryFrom<Vec<T>>>::try_from(items).map_err(|_| expected())
+	}
+}
+
+/// `null` or `undefined` to `None`.
+impl<T: FromValue> FromValue for Option<T> {
+	fn from_value(v: &Value) -> Option<Self> {
+		Self::try_from_value(v).ok()
+	}
+
+	fn try_from_value(v: &Value) -> Result<Self, ConversionError> {
+		if v.is_undefined() || v.is_null() || v.is_nothing() {
+			Ok(None)
+		} else {
+			T::try_from_value(v).map(Some)
+		}
+	}
+}
+
+// Map-like value to a map with string keys.
+fn map_from_value<T, M>(v: &Value) -> Result<M, ConversionError>
+where
+	T: FromValue,
+	M: ::std::iter::FromIterator<(String, T)>,
+{
+	if !(v.is_map() || v.is_object_map()) {
+		return Err(ConversionError::new("map", v));
+	}
+	v.items().into_iter()
+		.map(|(key, item)| {
+			let key = key.as_string().ok_or_else(|| ConversionError::new("string key", &key))?;
+			T::try_from_value(&item).map(|item| (key.clone(), item)).map_err(|e| e.at_key(&key))
+		})
+		.collect()
+}
+
+impl<T: FromValue, S: ::std::hash::BuildHasher + Default> FromValue for ::std::collections::HashMap<String, T, S> {
+	fn from_value(v: &Value) -> Option<Self> {
+		Self::try_from_value(v).ok()
+	}
+
+	fn try_from_value(v: &Value) -> Result<Self, ConversionError> {
+		map_from_value(v)
+	}
+}
+
+impl<T: FromValue> FromValue for ::std::collections::BTreeMap<String, T> {
+	fn from_value(v: &Value) -> Option<Self> {
+		Self::try_from_value(v).ok()
+	}
+
+	fn try_from_value(v: &Value) -> Result<Self, ConversionError> {
+		map_from_value(v)
+	}
+}
+
+/// Value from vector, as array.
+///
+/// Note that `Vec<u8>` is stored as an array of integers, use `&[u8]` for `T_BYTES`.
+impl<T: Into<Value>> From<Vec<T>> for Value {
+	fn from(val: Vec<T>) -> Self {
+		let mut v = Value::array(val.len());
+		for (i, item) in val.into_iter().enumerate() {
+			v.set(i, item);
+		}
+		v
+	}
+}
+
+/// Value from fixed-size array.
+impl<T: Into<Value>, const N: usize> From<[T; N]> for Value {
+	fn from(val: [T; N]) -> Self {
+		let mut v = Value::array(N);
+		for (i, item) in IntoIterator::into_iter(val).enumerate() {
+			v.set(i, item);
+		}
+		v
+	}
+}
+
+/// Value from `Option`, `None` is stored as `null`.
+impl<T: Into<Value>> From<Option<T>> for Value {
+	fn from(val: Option<T>) -> Self {
+		match val {
+			Some(v) => v.into(),
+			None => Value::null(),
+		}
+	}
+}
+
+/// Value from map with string keys.
+impl<T: Into<Value>, S> From<::std::collections::HashMap<String, T, S>> for Value {
+	fn from(val: ::std::collections::HashMap<String, T, S>) -> Self {
+		let mut v = Value::map();
+		for (key, item) in val {
+			v.set_item(key, item);
+		}
+		v
+	}
+}
+
+/// Value from map with string keys.
+impl<T: Into<Value>> From<::std::collections::BTreeMap<String, T>> for Value {
+	fn from(val: ::std::collections::BTreeMap<String, T>) -> Self {
+		let mut v = Value::map();
+		for (key, item) in val {
+			v.set_item(key, item);
+		}
+		v
+	}
+}
+
+// Tuples from and to arrays of the same length.
+macro_rules! tuple_value_impls {
+	($( $len:tt => ($($n:tt $name:ident)+) )+) => {
+		$(
+			impl<$($name: FromValue),+> FromValue for ($($name,)+) {
+				fn from_value(v: &Value) -> Option<Self> {
+					Self::try_from_value(v).ok()
+				}
+
+				fn try_from_value(v: &Value) -> Result<Self, ConversionError> {
+					if !(v.is_array() || v.is_object_array()) || v.len() != $len {
+						return Err(ConversionError::new(concat!("array of ", $len, " elements"), v));
+					}
+					Ok(($(
+						$name::try_from_value(&v.get($n)).map_err(|e| e.at_index($n))?,
+					)+))
+				}
+			}
+
+			/// Value from tuple, as array.
+			impl<$($name: Into<Value>),+> From<($($name,)+)> for Value {
+				fn from(val: ($($name,)+)) -> Self {
+					let mut v = Value::array($len);
+					$(
+						v.set($n, val.$n);
+					)+
+					v
+				}
+			}
+		)+
+	};
+}
+
+tuple_value_impls! {
+	1 => (0 A)
+	2 => (0 A 1 B)
+	3 => (0 A 1 B 2 C)
+	4 => (0 A 1 B 2 C 3 D)
+	5 => (0 A 1 B 2 C 3 D 4 E)
+	6 => (0 A 1 B 2 C 3 D 4 E 5 F)
+	7 => (0 A 1 B 2 C 3 D 4 E 5 F 6 G)
+	8 => (0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H)
+	9 => (0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H 8 I)
+	10 => (0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H 8 I 9 J)
+	11 => (0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H 8 I 9 J 10 K)
+	12 => (0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H 8 I 9 J 10 K 11 L)
+}
+
+
+/// A self-contained copy of a plain data `Value` tree.
+///
+/// Unlike `Value` it does not refer to any engine data, so it is `Send + Sync`
+/// and can be shared with worker threads.
+/// Script functions, objects, DOM elements and assets can not be copied this way.
+///
+/// The copy is lossless: variants keep the value units (the `u32` fields, e.g. string subtypes
+/// like [`VALUE_UNIT_TYPE_STRING::SYMBOL`](enum.VALUE_UNIT_TYPE_STRING.html)), so converting it back
+/// produces a value equal to the original one.
+///
+/// ```
+/// # #[macro_use] extern crate sciter;
+/// use std::convert::TryFrom;
+/// use sciter::value::{OwnedValue, Value};
+///
+/// # fn main() {
+/// let v = vmap! { "id" => 1, "tags" => varray!["a", "b"] };
+/// let owned = OwnedValue::try_from(&v).unwrap();
+///
+/// let back = std::thread::spawn(move || Value::from(owned)).join().unwrap();
+/// assert_eq!(back, v);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum OwnedValue {
+	/// `undefined`.
+	Undefined,
+	/// `nothing`.
+	Nothing,
+	/// `null`.
+	Null,
+	/// `true` or `false` and its units.
+	Bool(bool, u32),
+	/// 32-bit integer and its units.
+	Int(i32, u32),
+	/// Double float and its units.
+	Float(f64, u32),
+	/// String and its subtype, see [`VALUE_UNIT_TYPE_STRING`](enum.VALUE_UNIT_TYPE_STRING.html).
+	String(String, u32),
+	/// Array of bytes and its units.
+	Bytes(Vec<u8>, u32),
+	/// Array and its units, see [`VALUE_UNIT_TYPE_ARRAY`](enum.VALUE_UNIT_TYPE_ARRAY.html).
+	Array(Vec<OwnedValue>, u32),
+	/// Map as key-value pairs in their original order.
+	Map(Vec<(OwnedValue, OwnedValue)>),
+	/// Date and its units.
+	Date(::std::time::SystemTime, u32),
+	/// Currency.
+	Currency(Currency),
+	/// Color in `0xAABBGGRR` form.
+	Color(u32),
+	/// Duration and its units.
+	Duration(f64, u32),
+	/// Angle and its units.
+	Angle(f64, u32),
+	/// Length with units.
+	Length(Length),
+	/// Integer range.
+	Range(Range),
+}
+
+impl OwnedValue {
+	/// Plain string.
+	pub fn string(s: &str) -> OwnedValue {
+		OwnedValue::String(s.to_string(), VALUE_UNIT_TYPE_STRING::STRING as u32)
+	}
+
+	/// Symbol.
+	pub fn symbol(s: &str) -> OwnedValue {
+		OwnedValue::String(s.to_string(), VALUE_UNIT_TYPE_STRING::SYMBOL as u32)
+	}
+
+	/// Error string.
+	pub fn error(s: &str) -> OwnedValue {
+		OwnedValue::String(s.to_string(), VALUE_UNIT_TYPE_STRING::ERROR as u32)
+	}
+}
+
+/// Deep copy of a plain data value.
+///
+/// Fails on script functions, objects, DOM elements and assets, the error names the offending element.
+/// Lengths in units which are not covered by [`Length`](enum.Length.html) (i.e. `URL`) fail too.
+impl<'a> ::std::convert::TryFrom<&'a Value> for OwnedValue {
+	type Error = ConversionError;
+
+	fn try_from(v: &'a Value) -> Result<Self, ConversionError> {
+		use self::VALUE_TYPE::*;
+		let invalid = || ConversionError::new("plain data", v);
+		let units = v.data.u;
+		let owned = match v.data.t {
+			T_UNDEFINED if v.is_nothing() => OwnedValue::Nothing,
+			T_UNDEFINED => OwnedValue::Undefined,
+			T_NULL => OwnedValue::Null,
+			T_BOOL => OwnedValue::Bool(v.to_bool().ok_or_else(invalid)?, units),
+			T_INT => OwnedValue::Int(v.to_int().ok_or_else(invalid)?, units),
+			T_FLOAT => OwnedValue::Float(v.to_float().ok_or_else(invalid)?, units),
+			T_STRING => OwnedValue::String(v.as_string().ok_or_else(invalid)?, units),
+			T_BYTES => OwnedValue::Bytes(v.to_bytes().ok_or_else(invalid)?, units),
+			T_ARRAY => {
+				let items: Result<_, _> = v.values().enumerate()
+					.map(|(i, item)| OwnedValue::try_from(&item).map_err(|e| e.at_index(i)))
+					.collect();
+				OwnedValue::Array(items?, units)
+			},
+			T_MAP => {
+				let items: Result<_, _> = v.items().into_iter()
+					.map(|(key, item)| {
+						let name = key.as_string().unwrap_or_else(|| key.to_string());
+						let key = OwnedValue::try_from(&key).map_err(|e| e.at_key(&name))?;
+						let item = OwnedValue::try_from(&item).map_err(|e| e.at_key(&name))?;
+						Ok((key, item))
+					})
+					.collect();
+				OwnedValue::Map(items?)
+			},
+			T_DATE => OwnedValue::Date(v.to_date().ok_or_else(invalid)?, units),
+			T_CURRENCY => OwnedValue::Currency(v.to_currency().ok_or_else(invalid)?),
+			T_COLOR => OwnedValue::Color(v.to_color().ok_or_else(invalid)?),
+			T_DURATION => OwnedValue::Duration(v.to_duration().ok_or_else(invalid)?, units),
+			T_ANGLE => OwnedValue::Angle(v.to_angle().ok_or_else(invalid)?, units),
+			T_LENGTH => OwnedValue::Length(v.to_length().ok_or_else(invalid)?),
+			T_RANGE => OwnedValue::Range(v.to_range().ok_or_else(invalid)?),
+			_ => return Err(invalid()),
+		};
+		Ok(owned)
+	}
+}
+
+impl ::std::convert::TryFrom<Value> for OwnedValue {
+	type Error = ConversionError;
+
+	fn try_from(v: Value) -> Result<Self, ConversionError> {
+		OwnedValue::try_from(&v)
+	}
+}
+
+impl FromValue for OwnedValue {
+	fn from_value(v: &Value) -> Option<Self> {
+		Self::try_from_value(v).ok()
+	}
+
+	fn try_from_value(v: &Value) -> Result<Self, ConversionError> {
+		<OwnedValue as ::std::convert::TryFrom<&Value>>::try_from(v)
+	}
+}
+
+/// Value from a copied plain data tree.
+impl<'a> From<&'a OwnedValue> for Value {
+	fn from(val: &'a OwnedValue) -> Self {
+		// restore the original units of the value
+		fn with_units(mut v: Value, units: u32) -> Value {
+			v.data.u = units;
+			v
+		}
+		match *val {
+			OwnedValue::Undefined => Value::new(),
+			OwnedValue::Nothing => Value::nothing(),
+			OwnedValue::Null => Value::null(),
+			OwnedValue::Bool(b, u) => with_units(Value::from(b), u),
+			OwnedValue::Int(i, u) => with_units(Value::from(i), u),
+			OwnedValue::Float(f, u) => with_units(Value::from(f), u),
+			OwnedValue::String(ref s, u) => with_units(Value::from(s), u),
+			OwnedValue::Bytes(ref b, u) => with_units(Value::from(b.as_slice()), u),
+			OwnedValue::Array(ref items, u) => with_units(items.iter().map(Value::from).collect(), u),
+			OwnedValue::Map(ref items) => {
+				let mut map = Value::map();
+				for (key, item) in items {
+					map.set_item(Value::from(key), Value::from(item));
+				}
+				map
+			},
+			OwnedValue::Date(t, u) => with_units(Value::date(t, true), u),
+			OwnedValue::Currency(c) => Value::currency(c),
+			OwnedValue::Color(c) => Value::color(c),
+			OwnedValue::Duration(d, u) => with_units(Value::duration(d), u),
+			OwnedValue::Angle(a, u) => with_units(Value::angle(a), u),
+			OwnedValue::Length(l) => Value::length(l),
+			OwnedValue::Range(r) => Value::range(r),
+		}
+	}
+}
+
+/// Value from a copied plain data tree.
+impl From<OwnedValue> for Value {
+	fn from(val: OwnedValue) -> Self {
+		Value::from(&val)
+	}
 }
 
 
@@ -1258,6 +3347,49 @@ impl<'a> ::std::iter::DoubleEndedIterator for KeyIterator<'a> {
 }
 
 
+/// A lazy iterator over the key-value pairs of a map-like `Value`.
+#[doc(hidden)]
+pub struct ItemIterator<'a> {
+	base: &'a Value,
+	index: usize,
+	count: usize,
+}
+
+impl<'a> ::std::iter::Iterator for ItemIterator<'a> {
+	type Item = (Value, Value);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.index < self.count {
+			self.index += 1;
+			Some((self.base.key_at(self.index - 1), self.base.get(self.index - 1)))
+		} else {
+			None
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remain = self.count - self.index;
+		(remain, Some(remain))
+	}
+
+	fn count(self) -> usize {
+		self.count - self.index
+	}
+}
+
+/// An iterator able to yield key-value pairs from both `Value`'s ends.
+impl<'a> ::std::iter::DoubleEndedIterator for ItemIterator<'a> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.index == self.count || self.count == 0 {
+			None
+		} else {
+			self.count -= 1;
+			Some((self.base.key_at(self.count), self.base.get(self.count)))
+		}
+	}
+}
+
+
 /// An iterator over the sub-elements of a `Value`.
 #[doc(hidden)]
 pub struct SeqIterator<'a> {
@@ -1313,6 +3445,161 @@ impl<'a> ::std::iter::IntoIterator for &'a Value {
 }
 
 
+/// A promise for script, settled by Rust code from any thread.
+///
+/// Script gets a thenable object (with the `then(onFulfilled, onRejected)` method)
+/// which can be `await`ed, while Rust gets a `Send` [`Resolver`](struct.Resolver.html).
+/// The resolver posts the result to the UI thread of the window via `SciterPostCallback`,
+/// where the script callbacks are called.
+///
+/// ```no_run
+/// # use sciter::value::{Promise, Value};
+/// # let hwnd = ::std::ptr::null_mut();
+/// // e.g. in a native function called by script:
+/// let (promise, resolver) = Promise::new(hwnd);
+/// std::thread::spawn(move || {
+///   match std::fs::read("data.bin") {
+///     Ok(data) => resolver.resolve(data.len() as i32),
+///     Err(e) => resolver.reject(Value::error(&e.to_string())),
+///   }
+/// });
+/// let rv = Value::from(promise);
+/// ```
+pub struct Promise {
+	state: ::std::sync::Arc<::std::sync::Mutex<PromiseState>>,
+}
+
+/// Settles the [`Promise`](struct.Promise.html) on the UI thread.
+///
+/// A resolver that is dropped unsettled rejects the promise.
+pub struct Resolver {
+	hwnd: usize,
+	state: Option<::std::sync::Arc<::std::sync::Mutex<PromiseState>>>,
+}
+
+enum PromiseState {
+	/// `(onFulfilled, onRejected)` callbacks of the pending promise.
+	Pending(Vec<(Value, Value)>),
+	Settled(Result<Value, Value>),
+}
+
+/// Settlement posted to the UI thread.
+struct PostedSettlement {
+	state: ::std::sync::Arc<::std::sync::Mutex<PromiseState>>,
+	result: Result<Value, Value>,
+}
+
+/// `wparam` of the posted promise settlement notifications.
+const PROMISE_NOTIFICATION: UINT_PTR = 0x5052_4F4D;
+
+/// Settlements posted to the UI thread by their ids, passed as `lparam`.
+///
+/// Only the ids found here are settled, so foreign notifications with the same `wparam` are ignored.
+static POSTED_SETTLEMENTS: ::std::sync::Mutex<::std::collections::BTreeMap<UINT_PTR, PostedSettlement>>
+	= ::std::sync::Mutex::new(::std::collections::BTreeMap::new());
+
+/// The next id of `POSTED_SETTLEMENTS`.
+static NEXT_SETTLEMENT_ID: ::std::sync::atomic::AtomicUsize = ::std::sync::atomic::AtomicUsize::new(1);
+
+impl Promise {
+	/// Make a pending promise settled at the UI thread of the `hwnd` window.
+	pub fn new(hwnd: HWINDOW) -> (Promise, Resolver) {
+		let state = ::std::sync::Arc::new(::std::sync::Mutex::new(PromiseState::Pending(Vec::new())));
+		let resolver = Resolver { hwnd: hwnd as usize, state: Some(state.clone()) };
+		(Promise { state }, resolver)
+	}
+}
+
+/// Script thenable object of the promise.
+impl From<Promise> for Value {
+	fn from(promise: Promise) -> Value {
+		let state = promise.state;
+		let then = move |args: &[Value]| {
+			let on_fulfilled = args.first().cloned().unwrap_or_default();
+			let on_rejected = args.get(1).cloned().unwrap_or_default();
+			let settled = match *state.lock().unwrap() {
+				PromiseState::Pending(ref mut callbacks) => {
+					callbacks.push((on_fulfilled, on_rejected));
+					return Value::new();
+				},
+				PromiseState::Settled(ref result) => result.clone(),
+			};
+			notify_settled(&on_fulfilled, &on_rejected, &settled);
+			Value::new()
+		};
+		let mut me = Value::map();
+		me.set_item("then", then);
+		me
+	}
+}
+
+impl Resolver {
+	/// Fulfill the promise with `value`.
+	pub fn resolve<T: Into<Value>>(mut self, value: T) {
+		self.post(Ok(value.into()));
+	}
+
+	/// Reject the promise with `error`, usually a [`Value::error`](struct.Value.html#method.error).
+	pub fn reject<T: Into<Value>>(mut self, error: T) {
+		self.post(Err(error.into()));
+	}
+
+	/// Fulfill or reject the promise depending on `result`.
+	pub fn settle<T: Into<Value>, E: Into<Value>>(mut self, result: Result<T, E>) {
+		self.post(result.map(Into::into).map_err(Into::into));
+	}
+
+	fn post(&mut self, result: Result<Value, Value>) {
+		if let Some(state) = self.state.take() {
+			let id = NEXT_SETTLEMENT_ID.fetch_add(1, ::std::sync::atomic::Ordering::Relaxed) as UINT_PTR;
+			POSTED_SETTLEMENTS.lock().unwrap().insert(id, PostedSettlement { state, result });
+			let ok = (_API.SciterPostCallback)(self.hwnd as HWINDOW, PROMISE_NOTIFICATION, id, 0);
+			if ok == 0 {
+				// the window is gone, nobody will settle it
+				POSTED_SETTLEMENTS.lock().unwrap().remove(&id);
+			}
+		}
+	}
+}
+
+impl Drop for Resolver {
+	fn drop(&mut self) {
+		self.post(Err(Value::error("the promise was dropped without being settled")));
+	}
+}
+
+/// Settle the promise posted by `Resolver`, returns `false` for other notifications.
+pub(crate) fn settle_posted(wparam: UINT_PTR, lparam: UINT_PTR) -> bool {
+	if wparam != PROMISE_NOTIFICATION {
+		return false;
+	}
+	let posted = POSTED_SETTLEMENTS.lock().unwrap().remove(&lparam);
+	let PostedSettlement { state, result } = match posted {
+		Some(posted) => posted,
+		None => return false,
+	};
+	let settled = PromiseState::Settled(result.clone());
+	let callbacks = match ::std::mem::replace(&mut *state.lock().unwrap(), settled) {
+		PromiseState::Pending(callbacks) => callbacks,
+		PromiseState::Settled(_) => unreachable!("the promise is settled only once"),
+	};
+	for (on_fulfilled, on_rejected) in callbacks {
+		notify_settled(&on_fulfilled, &on_rejected, &result);
+	}
+	true
+}
+
+fn notify_settled(on_fulfilled: &Value, on_rejected: &Value, result: &Result<Value, Value>) {
+	let (callback, arg) = match *result {
+		Ok(ref value) => (on_fulfilled, value),
+		Err(ref error) => (on_rejected, error),
+	};
+	if !(callback.is_undefined() || callback.is_null()) {
+		// errors of the script callbacks are reported by the engine itself
+		let _ = callback.call(None, ::std::slice::from_ref(arg), None);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	#![allow(unused_imports)]
@@ -1355,4 +3642,12 @@ mod tests {
 		(_API.ValueClear)(&mut data);
 		assert_eq!(data.t, VALUE_TYPE::T_UNDEFINED);
 	}
+
+	#[test]
+	fn test_foreign_posted_notification() {
+		// not posted by a `Resolver`
+		assert!(!super::settle_posted(super::PROMISE_NOTIFICATION, 0));
+		assert!(!super::settle_posted(super::PROMISE_NOTIFICATION, 0xDEAD_BEEF));
+		assert!(!super::settle_posted(1, 0xDEAD_BEEF));
+	}
 }
@@ -7,9 +7,10 @@ Host application can render custom video streams using `<video>` infrastructure.
 
 use capi::sctypes::{UINT, LPCBYTE, LPCSTR};
 use capi::scom::som_passport_t;
+use error::Error;
 
-/// A type alias for Sciter functions that return `bool`.
-pub type Result<T> = ::std::result::Result<T, ()>;
+/// A specialized `Result` type for video operations.
+pub type Result<T> = ::std::result::Result<T, Error>;
 
 
 /// Color space for video frame.
@@ -56,13 +57,19 @@ macro_rules! cppcall {
 }
 
 macro_rules! cppresult {
-	( $( $t:tt )* ) => {
-		if cppcall!( $($t)* ) {
+	(const $this:ident . $func:ident $args:tt) => {
+		cppresult!(@check cppcall!(const $this . $func $args), $func)
+	};
+	($this:ident . $func:ident $args:tt) => {
+		cppresult!(@check cppcall!($this . $func $args), $func)
+	};
+	(@check $ok:expr, $func:ident) => {
+		if $ok {
 			Ok(())
 		} else {
-			Err(())
+			Err(Error::Failed { api: stringify!($func) })
 		}
-	}
+	};
 }
 
 #[doc(hidden)]
@@ -505,6 +512,10 @@ impl<T: NamedInterface> AssetPtr<T> {
 	/// ```
 	pub fn try_from<U>(other: &mut AssetPtr<U>) -> Result<Self> {
 		let me = T::query_interface(other.get());
-		me.map(|p| AssetPtr::adopt(p as *mut T)).ok_or(())
+		me.map(|p| AssetPtr::adopt(p as *mut T)).ok_or_else(|| {
+			let name = T::get_interface_name();
+			let name = ::std::str::from_utf8(&name[..name.len() - 1]).unwrap_or_default();
+			Error::NoInterface { name }
+		})
 	}
 }
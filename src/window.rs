@@ -30,9 +30,9 @@ use ::{_API};
 use capi::sctypes::*;
 
 use platform::{BaseWindow, OsWindow};
-use host::{Host, HostHandler};
+use host::{self, Host, HostHandler};
 use dom::{self, event::{EventHandler}};
-use crate::Value;
+use crate::{Error, Value};
 
 use std::rc::Rc;
 
@@ -221,7 +221,7 @@ impl Window {
 	///
 	/// See documentation of the [`Archive`](../host/struct.Archive.html).
 	///
-  pub fn archive_handler(&mut self, resource: &[u8]) -> Result<(), ()> {
+  pub fn archive_handler(&mut self, resource: &[u8]) -> host::Result<()> {
     self.host.register_archive(resource)
   }
 
@@ -321,7 +321,7 @@ impl Window {
 	}
 
 	/// Set various Sciter engine options, see the [`Options`](enum.Options.html).
-	pub fn set_options(&self, options: Options) -> Result<(), ()> {
+	pub fn set_options(&self, options: Options) -> host::Result<()> {
 		use capi::scdef::SCITER_RT_OPTIONS::*;
 		use self::Options::*;
 		let (option, value) = match options {
@@ -338,7 +338,7 @@ impl Window {
 		if ok != 0 {
 			Ok(())
 		} else {
-			Err(())
+			Err(Error::Failed { api: "SciterSetOption" })
 		}
 	}
 
@@ -356,7 +356,7 @@ impl Window {
 		if ok == dom::SCDOM_RESULT::OK {
 			Ok(())
 		} else {
-			Err(ok)
+			Err(Error::Dom { api: "SciterSetVariable", code: ok })
 		}
 	}
 
@@ -373,7 +373,7 @@ impl Window {
 		if ok == dom::SCDOM_RESULT::OK {
 			Ok(value)
 		} else {
-			Err(ok)
+			Err(Error::Dom { api: "SciterGetVariable", code: ok })
 		}
 	}
 
@@ -209,6 +209,73 @@ fn from_function_works() {
 	assert!(v.is_native_function());
 }
 
+#[test]
+fn from_typed_function_works() {
+	let v = Value::from_fn(|| 42);
+	assert!(v.is_native_function());
+	assert_eq!(v.call(None, &[], None), Ok(Value::from(42)));
+
+	let v = Value::from_fn(|name: String, times: u8, upper: Option<bool>| {
+		let s = name.repeat(times as usize);
+		if upper == Some(true) { s.to_uppercase() } else { s }
+	});
+	let args = [Value::from("ab"), Value::from(2), Value::null()];
+	assert_eq!(v.call(None, &args, None), Ok(Value::from("abab")));
+	let args = [Value::from("ab"), Value::from(1), Value::from(true)];
+	assert_eq!(v.call(None, &args, None), Ok(Value::from("AB")));
+
+	let e = v.call(None, &[Value::from("ab")], None).unwrap_err();
+	assert!(e.value().is_error_string());
+	assert_eq!(e.message(), "native function error: 1 of 3 arguments provided.");
+
+	let args = [Value::from("ab"), Value::from(300), Value::null()];
+	let e = v.call(None, &args, None).unwrap_err();
+	assert!(e.value().is_error_string());
+	assert!(e.message().starts_with("native function error: invalid type of 1 argument: expected u8, given "));
+
+	let sum = Value::from_fn(|items: Vec<f64>| items.iter().sum::<f64>());
+	assert_eq!(sum.call(None, &[varray![1.5, 2.5]], None), Ok(Value::from(4.0)));
+}
+
+#[test]
+fn promise_works() {
+	use std::sync::{Arc, Mutex};
+
+	let log = Arc::new(Mutex::new(Vec::new()));
+	let recorder = |prefix: &'static str| {
+		let log = log.clone();
+		Value::from(move |args: &[Value]| {
+			log.lock().unwrap().push(format!("{}: {}", prefix, args[0]));
+			Value::new()
+		})
+	};
+
+	let (promise, resolver) = Promise::new(std::ptr::null_mut());
+	let v = Value::from(promise);
+	let then = v.get_item("then");
+	assert!(then.is_native_function());
+	then.call(None, &[recorder("ok"), recorder("err")], None).unwrap();
+	assert!(log.lock().unwrap().is_empty());
+
+	std::thread::spawn(move || resolver.resolve(42)).join().unwrap();
+	assert_eq!(*log.lock().unwrap(), vec!["ok: 42"]);
+
+	// already settled
+	then.call(None, &[recorder("late")], None).unwrap();
+	assert_eq!(log.lock().unwrap().len(), 2);
+	assert_eq!(log.lock().unwrap()[1], "late: 42");
+
+	let (promise, resolver) = Promise::new(std::ptr::null_mut());
+	Value::from(promise).get_item("then").call(None, &[recorder("ok"), recorder("err")], None).unwrap();
+	resolver.settle::<i32, _>(Err(Value::error("failed")));
+	assert_eq!(log.lock().unwrap()[2], "err: \"failed\"");
+
+	let (promise, resolver) = Promise::new(std::ptr::null_mut());
+	Value::from(promise).get_item("then").call(None, &[Value::null(), recorder("dropped")], None).unwrap();
+	drop(resolver);
+	assert!(log.lock().unwrap()[3].starts_with("dropped: "));
+}
+
 #[test]
 fn from_result_works() {
 	// create Err variant
@@ -222,6 +289,366 @@ fn from_result_works() {
 	assert_eq!(v.to_int().unwrap(), 100);
 }
 
+#[test]
+fn from_value_containers_work() {
+	use std::collections::{BTreeMap, HashMap};
+
+	let v = Value::from(vec![1, 2, 3]);
+	assert_eq!(Vec::<i32>::from_value(&v), Some(vec![1, 2, 3]));
+	assert_eq!(<[u8; 3]>::from_value(&v), Some([1, 2, 3]));
+	assert_eq!(<[u8; 2]>::from_value(&v), None);
+
+	assert_eq!(Option::<String>::from_value(&Value::null()), Some(None));
+	assert_eq!(Option::<String>::from_value(&Value::from("7")), Some(Some("7".to_string())));
+
+	let v = Value::from((1, "two", 3.0));
+	assert_eq!(<(i32, String, f64)>::from_value(&v), Some((1, "two".to_string(), 3.0)));
+
+	let mut m = HashMap::new();
+	m.insert("one".to_string(), 1.0);
+	m.insert("two".to_string(), 2.0);
+	let v = Value::from(m.clone());
+	assert!(v.is_map());
+	assert_eq!(HashMap::<String, f64>::from_value(&v), Some(m));
+	assert_eq!(BTreeMap::<String, f64>::from_value(&v).map(|m| m.len()), Some(2));
+
+	let v = Value::from(&b"123"[..]);
+	assert_eq!(Vec::<u8>::from_value(&v), Some(b"123".to_vec()));
+}
+
+#[test]
+fn from_value_integers_work() {
+	assert_eq!(u8::from_value(&Value::from(255)), Some(255));
+	assert_eq!(u8::from_value(&Value::from(256)), None);
+	assert_eq!(i8::from_value(&Value::from(-129)), None);
+	assert_eq!(u32::from_value(&Value::from(-1)), None);
+
+	let v = Value::from(u32::MAX);
+	assert!(v.is_float());
+	assert_eq!(u32::from_value(&v), Some(u32::MAX));
+
+	let v = Value::from(1i64 << 40);
+	assert_eq!(i64::from_value(&v), Some(1i64 << 40));
+	assert_eq!(i64::from_value(&Value::from(0.5)), None);
+
+	// beyond 2^53 the stored float loses precision
+	assert_eq!(i64::from_value(&Value::from(1i64 << 53)), Some(1i64 << 53));
+	assert_eq!(i64::from_value(&Value::from((1i64 << 53) + 1)), Some(1i64 << 53));
+
+	assert_eq!(f32::from_value(&Value::from(0.5f32)), Some(0.5));
+}
+
+#[test]
+fn from_value_errors_work() {
+	let v = Value::from(vec![Value::from(1), Value::from("2")]);
+	let e = Vec::<i32>::try_from_value(&v).unwrap_err();
+	assert_eq!(e.path(), "[1]");
+	assert_eq!(e.expected(), "i32");
+
+	let v = vmap! { "items" => vmap! { "count" => vec![1, 300] } };
+	let e = std::collections::HashMap::<String, std::collections::HashMap<String, Vec<u8>>>::try_from_value(&v).unwrap_err();
+	assert_eq!(e.path(), "items.count[1]");
+	assert!(e.to_string().starts_with("items.count[1]: expected u8, given "));
+}
+
+#[test]
+fn date_works() {
+	use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+	let now = UNIX_EPOCH + Duration::from_micros(1_500_000_000_123_456);
+	let v = Value::from(now);
+	assert!(v.is_date());
+	assert_eq!(v.to_date(), Some(now));
+	assert_eq!(SystemTime::from_value(&v), Some(now));
+
+	let before = UNIX_EPOCH - Duration::from_secs(86400 * 365);
+	let v = Value::date(before, false);
+	assert_eq!(v.to_date(), Some(before));
+
+	let far = UNIX_EPOCH + Duration::from_secs(86400 * 365 * 600);
+	let v = Value::from(far);
+	assert_eq!(v.to_date(), Some(far));
+
+	// beyond `FILETIME` the dates are saturated
+	let huge = Duration::from_secs(1 << 40);
+	if let (Some(after), Some(later)) = (UNIX_EPOCH.checked_add(huge), UNIX_EPOCH.checked_add(huge * 2)) {
+		assert_eq!(Value::date(after, true), Value::date(later, true));
+		assert!(Value::date(after, true).to_date() < Some(after));
+	}
+	if let (Some(before), Some(earlier)) = (UNIX_EPOCH.checked_sub(huge), UNIX_EPOCH.checked_sub(huge * 2)) {
+		assert_eq!(Value::date(before, true), Value::date(earlier, true));
+	}
+
+	assert_eq!(Value::from(42).to_date(), None);
+	assert!(SystemTime::try_from_value(&Value::from("x")).is_err());
+}
+
+#[test]
+fn currency_works() {
+	let price: Currency = "12.34".parse().unwrap();
+	assert_eq!(price, Currency::from_raw(123400));
+	assert_eq!(price.to_string(), "12.3400");
+	assert_eq!("-0.5".parse::<Currency>().unwrap().to_string(), "-0.5000");
+	assert_eq!("7".parse::<Currency>().unwrap(), Currency::from_units(7));
+	assert!("1.23456".parse::<Currency>().is_err());
+	assert!("1.-2".parse::<Currency>().is_err());
+	assert!("-".parse::<Currency>().is_err());
+	assert_eq!(Currency::from_f64(0.1 + 0.2), Currency::from_raw(3000));
+
+	let v = Value::currency(price);
+	assert!(v.is_currency());
+	assert_eq!(v.to_currency(), Some(price));
+	assert_eq!(Currency::from_value(&v), Some(price));
+	assert_eq!(Value::from(Currency::from_units(-3)).into_string(), "-3.0000");
+
+	assert_eq!(Value::from(12).to_currency(), None);
+	assert!(Currency::try_from_value(&Value::from(12)).is_err());
+}
+
+#[test]
+fn length_works() {
+	let v = Value::from(Length::Percent(50.0));
+	assert!(v.is_length());
+	assert_eq!(v.to_length(), Some(Length::Percent(50.0)));
+	assert_eq!(v.to_float(), Some(50.0));
+
+	let dip = Length::new(1.5, VALUE_UNIT_TYPE_LENGTH::DIP).unwrap();
+	assert_eq!(dip, Length::Dip(1.5));
+	assert_eq!(Length::new(1.0, VALUE_UNIT_TYPE_LENGTH::URL), None);
+	assert_eq!(dip.unit(), VALUE_UNIT_TYPE_LENGTH::DIP);
+	assert_eq!(dip.to_string(), "1.5dip");
+	assert_eq!(Length::Flex(1.0).to_string(), "1*");
+
+	assert_eq!(" 1.5dip ".parse(), Ok(dip));
+	assert_eq!("50%".parse(), Ok(Length::Percent(50.0)));
+	assert_eq!("-2px".parse(), Ok(Length::Px(-2.0)));
+	assert!("12".parse::<Length>().is_err());
+	assert!("px".parse::<Length>().is_err());
+	assert_eq!("1zz".parse::<Length>().unwrap_err().to_string(), r#"invalid length "1zz""#);
+
+	assert_eq!(Length::from_value(&Value::length(Length::Em(2.0))), Some(Length::Em(2.0)));
+	assert_eq!(Value::from(12.0).to_length(), None);
+	assert!(Length::try_from_value(&Value::from(12)).is_err());
+}
+
+#[test]
+fn range_works() {
+	let v = Value::range(Range::new(-2, 7));
+	assert!(v.is_range());
+	assert_eq!(v.to_range(), Some(Range { start: -2, end: 7 }));
+
+	let r: std::ops::Range<i32> = Range::from_value(&Value::from(Range::from(0..3))).unwrap().into();
+	assert_eq!(r, 0..3);
+
+	assert_eq!(Value::from(1).to_range(), None);
+	assert!(Range::try_from_value(&Value::from("1..2")).is_err());
+}
+
+#[test]
+fn owned_value_works() {
+	use std::convert::TryFrom;
+
+	let mut v = vmap! {
+		"name" => "box",
+		"size" => varray![Length::Px(10.0), Length::Percent(50.0)],
+		"data" => b"\x01\x02".as_ref(),
+		"since" => std::time::UNIX_EPOCH,
+		"price" => Currency::from_raw(12345),
+		"empty" => Value::null(),
+	};
+	v.set_item(Value::symbol("kind"), Value::symbol("rect"));
+
+	let owned = OwnedValue::try_from(&v).unwrap();
+	match owned {
+		OwnedValue::Map(ref items) => {
+			assert_eq!(items.len(), 7);
+			assert_eq!(items[0], (OwnedValue::string("name"), OwnedValue::string("box")));
+			assert_eq!(items[6].1, OwnedValue::symbol("rect"));
+		},
+		_ => panic!("map expected"),
+	}
+
+	let copy = owned.clone();
+	let back = std::thread::spawn(move || Value::from(copy)).join().unwrap();
+	assert_eq!(back, v);
+	assert_eq!(OwnedValue::from_value(&back), Some(owned));
+
+	v.set_item("callback", Value::from(|_: &[Value]| Value::null()));
+	let v = varray![1, v];
+	let e = OwnedValue::try_from(v).unwrap_err();
+	assert_eq!(e.path(), "[1].callback");
+	assert_eq!(e.expected(), "plain data");
+}
+
+#[test]
+fn owned_value_round_trip() {
+	use std::convert::TryFrom;
+
+	fn with_units(mut v: Value, units: u32) -> Value {
+		unsafe { (*v.as_ptr()).u = units };
+		v
+	}
+
+	fn round_trip(v: Value) {
+		let owned = OwnedValue::try_from(&v).unwrap();
+		let back = Value::from(&owned);
+		assert_eq!(back, v, "{:?}", owned);
+		assert_eq!(back.full_type(), v.full_type(), "{:?}", owned);
+	}
+
+	round_trip(Value::new());
+	round_trip(Value::nothing());
+	round_trip(Value::null());
+	round_trip(with_units(Value::from(true), 2));
+	round_trip(with_units(Value::from(7), 3));
+	round_trip(with_units(Value::from(1.5), 4));
+	round_trip(Value::from("text"));
+	round_trip(Value::symbol("sym"));
+	round_trip(Value::error("oops"));
+	round_trip(with_units(Value::from("secret"), VALUE_UNIT_TYPE_STRING::SECURE as u32));
+	round_trip(with_units(Value::from("a.png"), VALUE_UNIT_TYPE_STRING::URL as u32));
+	round_trip(with_units(Value::from("div > p"), VALUE_UNIT_TYPE_STRING::SELECTOR as u32));
+	round_trip(with_units(Value::from("c:/file"), VALUE_UNIT_TYPE_STRING::FILE as u32));
+	round_trip(with_units(Value::from(b"\x01\x02".as_ref()), 1));
+	round_trip(with_units(varray![1, "2"], VALUE_UNIT_TYPE_ARRAY::CS_LIST as u32));
+	round_trip(vmap! { "a" => 1, "b" => varray![] });
+	round_trip(Value::date(std::time::UNIX_EPOCH, true));
+	round_trip(Value::currency(Currency::from_raw(12345)));
+	round_trip(Value::color(0xFF00_FF00));
+	round_trip(with_units(Value::duration(0.5), 1));
+	round_trip(with_units(Value::angle(1.25), 1));
+	round_trip(Value::length(Length::Em(2.0)));
+	round_trip(Value::range(Range::new(1, 10)));
+}
+
+#[test]
+fn path_works() {
+	let mut v: Value = "{user: {name: 'Jo', 'a/b': 1, addresses: [{city: 'Oslo'}, {city: 'Rome'}]}}".parse().unwrap();
+
+	assert_eq!(v.pointer(""), Some(v.clone()));
+	assert_eq!(v.pointer("/user/name"), Some(Value::from("Jo")));
+	assert_eq!(v.pointer("/user/a~1b"), Some(Value::from(1)));
+	assert_eq!(v.pointer("/user/addresses/1/city"), Some(Value::from("Rome")));
+	assert_eq!(v.pointer("/user/addresses/2/city"), None);
+	assert_eq!(v.pointer("/user/name/first"), None);
+	assert_eq!(v.pointer("user"), None);
+
+	assert_eq!(v.get_path("user.addresses[0].city"), Some(Value::from("Oslo")));
+	assert_eq!(v.get_path("user.addresses.0.city"), Some(Value::from("Oslo")));
+	assert_eq!(v.get_path("/user/addresses/0/city"), Some(Value::from("Oslo")));
+	assert_eq!(v.get_path("user.addresses[x]"), None);
+	assert_eq!(v.get_path("user..name"), None);
+
+	// results are independent copies
+	let city = v.pointer("/user/addresses/1/city");
+	let name = v.pointer("/user/name");
+	assert_eq!(city, Some(Value::from("Rome")));
+	assert_eq!(name, Some(Value::from("Jo")));
+
+	// nested containers are shared
+	v.pointer("/user/addresses").unwrap().push(vmap! { "city" => "Nice" });
+	assert_eq!(v.get_path("user.addresses[2].city"), Some(Value::from("Nice")));
+	v.get_path("user.addresses[2]").unwrap().set_item("zip", 6000);
+	assert_eq!(v.pointer("/user/addresses/2/zip"), Some(Value::from(6000)));
+
+	// guards store modified values back by the path
+	*v.pointer_mut("/user/addresses/0/city").unwrap() = Value::from("Bergen");
+	assert_eq!(v.get_path("user.addresses[0].city"), Some(Value::from("Bergen")));
+	{
+		let mut name = v.get_path_mut("user.name").unwrap();
+		assert_eq!(*name, Value::from("Jo"));
+		*name = Value::from(name.as_string().unwrap() + "hn");
+	}
+	assert_eq!(v.get_path("user.name"), Some(Value::from("John")));
+	assert!(v.pointer_mut("/user/phones/0").is_none());
+	assert!(v.get_path_mut("user[").is_none());
+
+	v.set_path("user.name", "Jane").unwrap();
+	v.set_path("/user/tags/-", "admin").unwrap();
+	v.set_path("user.matrix[1][0]", 5).unwrap();
+	v.set_path("settings.theme", "dark").unwrap();
+	assert_eq!(v.get_path("user.name"), Some(Value::from("Jane")));
+	assert_eq!(v.get_path("user.tags[0]"), Some(Value::from("admin")));
+	assert!(v.get_path("user.matrix").unwrap().is_array());
+	assert_eq!(v.get_path("user.matrix[1][0]"), Some(Value::from(5)));
+	assert_eq!(v.get_path("settings.theme"), Some(Value::from("dark")));
+
+	assert_eq!(v.set_path("user.name.first", "J"), Err(VALUE_RESULT::INCOMPATIBLE_TYPE));
+	assert_eq!(v.set_path("user.tags.x", 1), Err(VALUE_RESULT::INCOMPATIBLE_TYPE));
+	assert_eq!(v.set_path("user[", 1), Err(VALUE_RESULT::BAD_PARAMETER));
+
+	let mut root = Value::new();
+	root.set_path("", 1).unwrap();
+	assert_eq!(root, Value::from(1));
+}
+
+#[test]
+fn map_entry_works() {
+	let mut v: Value = vec![("one", 1), ("two", 2)].into_iter().collect();
+	assert!(v.is_map());
+	assert_eq!(v.len(), 2);
+
+	v.extend(vec![("two", Value::from(22)), ("three", Value::from(3))]);
+	assert_eq!(v.len(), 3);
+	assert_eq!(v["two"], Value::from(22));
+
+	let items: Vec<(Value, Value)> = v.iter_items().collect();
+	assert_eq!(items, v.items());
+	let mut it = v.iter_items();
+	assert_eq!(it.next_back(), Some((Value::from("three"), Value::from(3))));
+	assert_eq!(it.count(), 2);
+
+	assert!(v.contains_key("one"));
+	assert!(!v.contains_key("four"));
+	let parsed: Value = "{one: 1}".parse().unwrap();
+	assert!(parsed.contains_key("one"));
+
+	assert_eq!(*v.entry("one").or_insert(100), Value::from(1));
+	assert_eq!(*v.entry("four").or_insert_with(|| 4), Value::from(4));
+	assert_eq!(v["four"], Value::from(4));
+	{
+		let mut four = v.entry("four").or_insert(0);
+		*four = Value::from(four.to_int().unwrap() * 10);
+	}
+	assert_eq!(v["four"], Value::from(40));
+	assert!(v.entry("six").or_default().is_undefined());
+	assert!(!v.contains_key("six"));
+	v.entry("one").and_modify(|n| *n = Value::from(n.to_int().unwrap() + 10));
+	assert_eq!(v["one"], Value::from(11));
+	// a read-only guard does not store its copy back
+	let mut shared = v.clone();
+	{
+		let one = v.entry("one").or_insert(0);
+		shared.set_item("one", 111);
+		assert_eq!(*one, Value::from(11));
+	}
+	assert_eq!(v["one"], Value::from(111));
+
+	v.set_item("nothing", Value::null());
+	match v.entry("nothing") {
+		Entry::Occupied(mut e) => {
+			assert_eq!(e.key(), &Value::from("nothing"));
+			assert_eq!(e.insert(0), Value::null());
+		},
+		Entry::Vacant(_) => panic!("key must be present"),
+	}
+	match v.entry("five") {
+		Entry::Vacant(e) => { e.insert(5); },
+		Entry::Occupied(_) => panic!("key must be absent"),
+	}
+	match v.entry("six") {
+		Entry::Vacant(_) => {},
+		Entry::Occupied(_) => panic!("undefined must be vacant"),
+	}
+	assert_eq!(v.len(), 7);
+
+	let rest = v.without_item("two").without_item("three").without_item("six");
+	assert!(v.contains_key("two"));
+	assert!(!rest.contains_key("two"));
+	let keys: Vec<Value> = rest.keys().collect();
+	assert_eq!(keys, vec![Value::from("one"), Value::from("four"), Value::from("nothing"), Value::from("five")]);
+}
+
 #[test]
 fn parse_works() {
 	let items = ["", "null", "1", "\"2\"", "2.0", "true", "[3, 4]", r##"{"5": 5, "6": 6, seven: "seven"}"##];
@@ -249,6 +676,343 @@ fn parse_fail_works() {
 	}
 }
 
+#[test]
+fn to_string_as_works() {
+	let v = Value::parse("[1, \"two\", 3.0]").unwrap();
+	assert_eq!(Value::parse(&v.to_string_as(VALUE_STRING_CVT_TYPE::JSON_LITERAL)), Ok(v));
+
+	let v = Value::from("plain");
+	assert_eq!(v.to_string_as(VALUE_STRING_CVT_TYPE::SIMPLE), "plain");
+}
+
+#[test]
+fn json_string_works() {
+	let mut v = Value::map();
+	v.set_item("int", 1);
+	v.set_item("float", 1.0);
+	v.set_item("text", "a \"quoted\"\n");
+	v.set_item("list", varray![true, Value::null(), Value::new()]);
+	v.set_item("empty", Value::array(0));
+	assert_eq!(v.to_json_string(false), r#"{"int":1,"float":1.0,"text":"a \"quoted\"\n","list":[true,null,null],"empty":[]}"#);
+
+	let v = vmap! { "a" => varray![1, 2], "b" => vmap! {} };
+	assert_eq!(v.to_json_string(true), "{\n  \"a\": [\n    1,\n    2\n  ],\n  \"b\": {}\n}");
+
+	// Sciter-specific types
+	use std::time::{Duration, UNIX_EPOCH};
+	let date = UNIX_EPOCH + Duration::new(1_500_000_000, 123_456_700);
+	let v = varray![
+		Value::color(0xFF00_00FF),
+		Value::date(date, true),
+		Value::currency(Currency::from_raw(123_400)),
+		Value::length(Length::Px(12.0)),
+		Value::range(Range::new(1, 10)),
+		Value::duration(0.5),
+		Value::angle(1.5),
+		Value::from(b"hello".as_ref()),
+		Value::symbol("name"),
+		Value::error("failed")
+	];
+	assert_eq!(v.to_json_string(false), concat!(r#"[{"$color":4278190335},{"$date":"2017-07-14T02:40:00.1234567Z"},"#,
+		r#"{"$currency":"12.3400"},{"$length":"12px"},{"$range":[1,10]},{"$duration":0.5},{"$angle":1.5},"#,
+		r#"{"$bytes":"aGVsbG8="},{"$symbol":"name"},{"$error":"failed"}]"#));
+
+	// no JSON representation
+	let v = Value::from(|_: &[Value]| Value::new());
+	assert_eq!(v.to_json_string(false), "null");
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn serde_json_works() {
+	extern crate serde_json;
+	use std::convert::TryFrom;
+
+	let json: serde_json::Value = serde_json::from_str(r#"{"a": [1, 2.5, "x", null, true], "b": {"c": 1.0}}"#).unwrap();
+	let v = Value::from(&json);
+	assert_eq!(v["a"][0], Value::from(1));
+	assert_eq!(v["a"][1], Value::from(2.5));
+	assert!(v["b"]["c"].is_float());
+	assert_eq!(serde_json::Value::try_from(&v).unwrap(), json);
+
+	// integers out of the `i32` range are stored as floats
+	let v = Value::from(serde_json::Value::from(12_345_678_901_i64));
+	assert_eq!(v, Value::from(12_345_678_901.0));
+
+	// reversible mapping of Sciter-specific types
+	use std::time::{Duration, UNIX_EPOCH};
+	let date = UNIX_EPOCH + Duration::new(1_500_000_000, 123_456_700);
+	let v = varray![
+		Value::color(0xFF00_00FF),
+		Value::date(date, true),
+		Value::date(UNIX_EPOCH - Duration::from_secs(86_400 * 365), true),
+		Value::currency(Currency::from_raw(-5)),
+		Value::length(Length::Percent(50.0)),
+		Value::range(Range::new(-1, 10)),
+		Value::duration(0.5),
+		Value::angle(1.5),
+		Value::from(b"bytes!".as_ref()),
+		Value::symbol("name"),
+		Value::error("failed")
+	];
+	let json = serde_json::Value::try_from(&v).unwrap();
+	assert_eq!(Value::from(&json), v);
+	assert_eq!(Value::from(serde_json::from_str::<serde_json::Value>(&v.to_json_string(true)).unwrap()), v);
+
+	// plain maps with a reserved key are escaped and come back as maps
+	for v in &[vmap! { "$color" => 255 }, vmap! { "$error" => "x" }, vmap! { "$map" => vmap! { "$date" => 1 } }] {
+		assert_eq!(&Value::from(serde_json::from_str::<serde_json::Value>(&v.to_json_string(false)).unwrap()), v);
+		assert_eq!(&Value::from(serde_json::Value::try_from(v).unwrap()), v);
+	}
+	assert_eq!(vmap! { "$color" => 255 }.to_json_string(false), r#"{"$map":{"$color":255}}"#);
+
+	// no JSON representation
+	let v = vmap! { "f" => Value::from(|_: &[Value]| Value::new()) };
+	let e = serde_json::Value::try_from(&v).unwrap_err();
+	assert_eq!(e.path(), "f");
+}
+
+#[test]
+fn value_macro_works() {
+	assert_eq!(value!(null), Value::null());
+	assert!(value!(undefined).is_undefined());
+	assert!(value!(nothing).is_nothing());
+	assert_eq!(value!(7), Value::from(7));
+	assert_eq!(value!([]), Value::array(0));
+	assert_eq!(value!({}), Value::map());
+
+	let v = value!({"a": [1, 2.0, {"b": null}], "c": color(0xff0000)});
+	assert_eq!(v.len(), 2);
+	assert_eq!(v["a"].len(), 3);
+	assert_eq!(v["a"][0], Value::from(1));
+	assert_eq!(v["a"][1], Value::from(2.0));
+	assert_eq!(v["a"][2]["b"], Value::null());
+	assert_eq!(v["c"].to_color(), Some(0xff0000));
+
+	// the same via the flat macros
+	assert_eq!(v, vmap! { "a" => varray![1, 2.0, vmap! { "b" => Value::null() }], "c" => Value::color(0xff0000) });
+
+	// typed constructors
+	let v = value!([
+		duration(0.5), angle(1.0), symbol("name"), error("failed"),
+		length(Length::Px(1.0)), range(Range::new(1, 2)), currency(Currency::from_units(1)),
+		date(std::time::UNIX_EPOCH, true),
+		undefined, nothing, [[]], {"x": {"y": [null]}},
+	]);
+	assert_eq!(v.len(), 12);
+	assert!(v[0].is_duration());
+	assert!(v[1].is_angle());
+	assert!(v[2].is_symbol());
+	assert!(v[3].is_error_string());
+	assert!(v[4].is_length());
+	assert!(v[5].is_range());
+	assert!(v[6].is_currency());
+	assert!(v[7].is_date());
+	assert!(v[8].is_undefined());
+	assert!(v[9].is_nothing());
+	assert_eq!(v[10][0], Value::array(0));
+	assert_eq!(v[11]["x"]["y"][0], Value::null());
+
+	// interpolation
+	fn twice(n: i32) -> i32 { n * 2 }
+	let key = "key";
+	let items = [1, 2, 3];
+	let v = value!({
+		key: items.iter().sum::<i32>(),
+		(format!("{}-{}", key, 2)): -1,
+		"call": twice(2),
+		"inner": v[2].clone(),
+		"list": [items.len() as i32, twice(1), (twice(1) + 1), "text".to_string()]
+	});
+	assert_eq!(v["key"], Value::from(6));
+	assert_eq!(v["key-2"], Value::from(-1));
+	assert_eq!(v["call"], Value::from(4));
+	assert!(v["inner"].is_symbol());
+	assert_eq!(v["list"], varray![3, 2, 3, "text"]);
+}
+
+#[test]
+fn ord_and_hash_work() {
+	use std::collections::HashSet;
+	use std::collections::hash_map::DefaultHasher;
+	use std::hash::{Hash, Hasher};
+
+	fn hash(v: &Value) -> u64 {
+		let mut h = DefaultHasher::new();
+		v.hash(&mut h);
+		h.finish()
+	}
+
+	// NaN
+	let nan = Value::from(f64::NAN);
+	assert_eq!(nan, nan.clone());
+	assert_eq!(nan, Value::from(-f64::NAN));
+	assert_eq!(hash(&nan), hash(&Value::from(-f64::NAN)));
+	assert!(nan > Value::from(f64::INFINITY));
+	assert_eq!(Value::from(0.0), Value::from(-0.0));
+	assert_eq!(hash(&Value::from(0.0)), hash(&Value::from(-0.0)));
+
+	// types and units
+	assert_ne!(Value::from(1), Value::from(1.0));
+	assert_ne!(Value::from(1), Value::from(true));
+	assert_ne!(Value::from("a"), Value::symbol("a"));
+	assert_ne!(Value::length(Length::Px(1.0)), Value::length(Length::Em(1.0)));
+	assert!(Value::new() < Value::null());
+	assert!(Value::null() < Value::from(false));
+	assert!(Value::from(-1) < Value::from(1));
+	assert!(Value::from("a") < Value::from("b"));
+	assert!(varray![1, 2] < varray![1, 2, 0]);
+	assert!(varray![1, 2] < varray![1, 3]);
+
+	// maps regardless of the key order
+	let a = vmap! { "a" => 1, "b" => varray![1, 2] };
+	let b = vmap! { "b" => varray![1, 2], "a" => 1 };
+	assert_eq!(a, b);
+	assert_eq!(hash(&a), hash(&b));
+	assert_ne!(a, vmap! { "a" => 1, "b" => varray![2, 1] });
+
+	let set: HashSet<Value> = vec![a, b, Value::from(1), Value::from(1), nan.clone(), nan].into_iter().collect();
+	assert_eq!(set.len(), 3);
+
+	let mut list = vec![Value::from("x"), Value::from(2), Value::null(), Value::from(1), Value::new()];
+	list.sort();
+	assert_eq!(list, vec![Value::new(), Value::null(), Value::from(1), Value::from(2), Value::from("x")]);
+}
+
+#[test]
+fn diff_works() {
+	use sciter::value::Change;
+
+	let old = value!({"name": "Bob", "tags": ["a", "b"], "address": {"city": "Paris", "zip": 1}, "a/b": 1});
+	let new = value!({"name": "Bob", "tags": ["a", "c", "d"], "address": {"city": "Paris"}, "a/b": 2, "age": 30});
+
+	assert!(old.diff(&old.clone()).is_empty());
+	let changes = old.diff(&new);
+	assert_eq!(changes, vec![
+		Change::Changed { path: "/tags/1".to_string(), old: Value::from("b"), new: Value::from("c") },
+		Change::Added { path: "/tags/2".to_string(), value: Value::from("d") },
+		Change::Removed { path: "/address/zip".to_string(), value: Value::from(1) },
+		Change::Changed { path: "/a~1b".to_string(), old: Value::from(1), new: Value::from(2) },
+		Change::Added { path: "/age".to_string(), value: Value::from(30) },
+	]);
+	for change in &changes {
+		if let Change::Added { ref path, ref value } = *change {
+			assert_eq!(new.pointer(path).as_ref(), Some(value));
+		}
+	}
+
+	// different types are replaced as a whole
+	assert_eq!(Value::from(1).diff(&varray![1]), vec![
+		Change::Changed { path: String::new(), old: Value::from(1), new: varray![1] },
+	]);
+	assert_eq!(changes[1].path(), "/tags/2");
+}
+
+#[test]
+fn script_error_works() {
+	let e = ScriptError::new("failed");
+	assert_eq!(e.message(), "failed");
+	assert_eq!(e.url(), None);
+	assert_eq!(e.to_string(), "failed");
+	assert!(e.value().is_error_string());
+
+	// error object
+	let thrown = vmap! {
+		"message" => "x is not defined",
+		"fileName" => "main.js",
+		"lineNumber" => 12,
+		"columnNumber" => 5,
+		"stack" => "at foo (main.js:12:5)",
+	};
+	let e = ScriptError::from_value(thrown.clone());
+	assert_eq!(e.message(), "x is not defined");
+	assert_eq!(e.url(), Some("main.js"));
+	assert_eq!(e.line(), Some(12));
+	assert_eq!(e.column(), Some(5));
+	assert_eq!(e.stack(), Some("at foo (main.js:12:5)"));
+	assert_eq!(e.value(), &thrown);
+	assert_eq!(e.to_string(), "x is not defined at main.js:12:5");
+
+	// any thrown value
+	let e = ScriptError::from_value(Value::from(42));
+	assert_eq!(e.message(), "42");
+	assert_eq!(e.line(), None);
+
+	// a native function returning an error
+	let f = Value::from(|_: &[Value]| Value::error("bad argument"));
+	let e = f.call(None, &[], None).unwrap_err();
+	assert_eq!(e.message(), "bad argument");
+	let e: Box<dyn std::error::Error> = Box::new(e);
+	assert_eq!(e.to_string(), "bad argument");
+
+	// not a function: nothing is thrown, the failed call is reported
+	let e = Value::from(1).call(None, &[], None).unwrap_err();
+	let failed = sciter::Error::Value { api: "ValueInvoke", code: VALUE_RESULT::INCOMPATIBLE_TYPE };
+	assert_eq!(e.api_error(), Some(&failed));
+	assert_eq!(e.to_string(), "script call failed");
+	assert_eq!(std::error::Error::source(&e).unwrap().to_string(), "ValueInvoke failed: INCOMPATIBLE_TYPE");
+	assert_eq!(sciter::Error::from(e), failed);
+	assert_eq!(ScriptError::new("failed").api_error(), None);
+}
+
+#[test]
+fn crate_error_works() {
+	use sciter::Error;
+	use sciter::dom::SCDOM_RESULT;
+	use sciter::graphics::GRAPHIN_RESULT;
+	use std::error::Error as StdError;
+
+	let e = Error::Graphics { api: "imageCreate", code: GRAPHIN_RESULT::BAD_PARAM };
+	assert_eq!(e.api(), "imageCreate");
+	assert_eq!(e.code(), Some(1));
+	assert_eq!(e.to_string(), "imageCreate failed: BAD_PARAM");
+	assert!(e.source().is_none());
+
+	let e = Error::Failed { api: "SciterSetOption" };
+	assert_eq!(e.code(), None);
+	assert_eq!(e.to_string(), "SciterSetOption failed");
+
+	let e = Error::NoInterface { name: "source.video.sciter.com" };
+	assert_eq!(e.api(), "get_interface");
+	assert_eq!(e.to_string(), r#"the asset does not support the "source.video.sciter.com" interface"#);
+
+	// the emulated API can't load a library
+	let e = sciter::set_options(sciter::RuntimeOptions::LibraryPath("sciter.dll")).unwrap_err();
+	match e {
+		Error::Library(ref message) => assert!(message.contains("emulated")),
+		_ => panic!("unexpected {:?}", e),
+	}
+	assert!(e.to_string().starts_with("can't load the Sciter library: "));
+
+	// bare codes
+	let e = Error::from(SCDOM_RESULT::INVALID_HANDLE);
+	assert_eq!(e, Error::Dom { api: "", code: SCDOM_RESULT::INVALID_HANDLE });
+	assert_eq!(e.to_string(), "Sciter API failed: INVALID_HANDLE");
+	assert_eq!(Error::from(VALUE_RESULT::BAD_PARAMETER).code(), Some(1));
+
+	// propagation
+	fn call(f: &Value) -> Result<Value, Error> {
+		let rv = f.call(None, &[], None)?;
+		let n = i32::try_from_value(&rv)?;
+		Ok(Value::from(n))
+	}
+	assert_eq!(call(&Value::from(|_: &[Value]| Value::from(1))), Ok(Value::from(1)));
+
+	let e = call(&Value::from(|_: &[Value]| Value::error("failed"))).unwrap_err();
+	assert_eq!(e.to_string(), "script error");
+	assert_eq!(e.source().unwrap().to_string(), "failed");
+
+	let e = call(&Value::from(|_: &[Value]| Value::from("1"))).unwrap_err();
+	assert!(matches!(e, Error::Conversion(_)));
+	assert_eq!(e.to_string(), "conversion error");
+	assert!(e.source().unwrap().to_string().starts_with("expected "));
+
+	fn boxed() -> Result<(), Box<dyn StdError>> {
+		Err(Error::Failed { api: "SciterOpenArchive" })?
+	}
+	assert_eq!(boxed().unwrap_err().to_string(), "SciterOpenArchive failed");
+}
+
 #[test]
 fn pack_args_works() {
 	let args = pack_args!();
@@ -284,6 +1048,28 @@ fn make_args_works() {
 	assert_eq!(args.len(), 3);
 }
 
+#[test]
+fn into_args_works() {
+	assert!(().into_args().is_empty());
+
+	let args = (777,).into_args();
+	assert_eq!(args, vec![Value::from(777)]);
+
+	let args = (1, "2", 3.0, true).into_args();
+	assert_eq!(args, vec![Value::from(1), Value::from("2"), Value::from(3.0), Value::from(true)]);
+
+	let args = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11).into_args();
+	assert_eq!(args.len(), 12);
+	assert_eq!(args[11], Value::from(11));
+
+	let args = make_args!(1, "2").into_args();
+	assert_eq!(args, vec![Value::from(1), Value::from("2")]);
+
+	let list = vec![Value::from(1), Value::from(2)];
+	assert_eq!(list.as_slice().into_args(), list);
+	assert_eq!(list.clone().into_args(), list);
+}
+
 #[test]
 fn append_works() {
 	let mut v = Value::new();